@@ -0,0 +1,22 @@
+import Foundation
+@testable import Orchestrana
+
+extension AppState {
+    /// An app state backed by a throwaway defaults suite and in-memory history, so tests never read
+    /// or write real settings or sessions.
+    static func makeForTesting(durationConfig: DurationConfig = .standard) -> AppState {
+        let suiteName = "OrchestranaTests.\(UUID().uuidString)"
+        let defaults = UserDefaults(suiteName: suiteName)!
+        defaults.removePersistentDomain(forName: suiteName)
+        let analytics = ProductivityAnalyticsStore.inMemory()
+        return AppState(
+            pomodoro: PomodoroTimerEngine(durationConfig: durationConfig),
+            countdown: CountdownTimerEngine(durationConfig: durationConfig),
+            durationConfig: durationConfig,
+            userDefaults: defaults,
+            ambientNoiseEngine: AmbientNoiseEngine(),
+            sessionRecordStore: .inMemory(analytics: analytics),
+            analyticsStore: analytics
+        )
+    }
+}
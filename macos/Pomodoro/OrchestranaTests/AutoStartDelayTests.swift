@@ -0,0 +1,74 @@
+import XCTest
+@testable import Orchestrana
+
+final class AutoStartDelayTests: XCTestCase {
+    private func finishSession(_ engine: PomodoroTimerEngine) {
+        engine.adjustRemaining(bySeconds: -engine.remainingSeconds)
+    }
+
+    func testWorkToBreakWaitsForTheBreakDelay() {
+        let engine = PomodoroTimerEngine()
+        engine.autoStartDelayWorkSeconds = 30
+        engine.autoStartDelayBreakSeconds = 10
+        engine.start()
+
+        finishSession(engine)
+
+        XCTAssertEqual(engine.state, .running)
+        XCTAssertEqual(engine.autoStartGraceState(), AutoStartGraceState(upcomingMode: .breakTime, secondsLeft: 10))
+        engine.reset()
+    }
+
+    func testBreakToWorkWaitsForTheWorkDelay() {
+        let engine = PomodoroTimerEngine()
+        engine.autoStartDelayWorkSeconds = 30
+        engine.autoStartDelayBreakSeconds = 10
+        engine.start()
+        engine.startBreak()
+
+        finishSession(engine)
+
+        XCTAssertEqual(engine.state, .breakRunning)
+        XCTAssertEqual(engine.autoStartGraceState(), AutoStartGraceState(upcomingMode: .work, secondsLeft: 30))
+        engine.reset()
+    }
+
+    func testNoDelayOnOneSideTransitionsAtOnce() {
+        let engine = PomodoroTimerEngine()
+        engine.autoStartDelayWorkSeconds = 30
+        engine.start()
+
+        finishSession(engine)
+        XCTAssertEqual(engine.state, .breakRunning)
+        XCTAssertNil(engine.autoStartGraceState())
+        engine.reset()
+    }
+
+    func testBreakStartsOnceItsDelayHasPassed() {
+        let engine = PomodoroTimerEngine()
+        engine.autoStartDelayWorkSeconds = 30
+        engine.autoStartDelayBreakSeconds = 1
+        engine.start()
+
+        finishSession(engine)
+        let delayPassed = expectation(description: "break delay passed")
+        DispatchQueue.main.asyncAfter(deadline: .now() + 1.5) {
+            delayPassed.fulfill()
+        }
+        wait(for: [delayPassed], timeout: 3)
+        XCTAssertEqual(engine.state, .breakRunning)
+        XCTAssertEqual(engine.mode, .breakTime)
+        engine.reset()
+    }
+
+    func testSetAutoStartDelaySetsBothDelays() {
+        let engine = PomodoroTimerEngine()
+        engine.setAutoStartDelay(15)
+        XCTAssertEqual(engine.autoStartDelayWorkSeconds, 15)
+        XCTAssertEqual(engine.autoStartDelayBreakSeconds, 15)
+
+        engine.setAutoStartDelay(-5)
+        XCTAssertEqual(engine.autoStartDelayWorkSeconds, 0)
+        XCTAssertEqual(engine.autoStartDelayBreakSeconds, 0)
+    }
+}
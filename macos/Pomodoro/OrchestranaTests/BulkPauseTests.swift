@@ -0,0 +1,57 @@
+import XCTest
+@testable import Orchestrana
+
+final class BulkPauseTests: XCTestCase {
+    func testResumeAllResumesOnlyTimersThatWereRunning() {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+        appState.startCountdown()
+        appState.pauseCountdown()
+
+        appState.pauseAllTimers()
+        XCTAssertEqual(appState.pomodoro.state, .paused)
+        XCTAssertTrue(appState.hasBulkPausedTimers)
+
+        appState.resumeAllTimers()
+        XCTAssertEqual(appState.pomodoro.state, .running)
+        XCTAssertEqual(appState.countdown.state, .paused)
+        XCTAssertFalse(appState.hasBulkPausedTimers)
+    }
+
+    func testResumeAllNeverStartsAStoppedCountdown() {
+        let appState = AppState.makeForTesting()
+        appState.startCountdown()
+
+        appState.pauseAllTimers()
+        appState.resetCountdown()
+        appState.resumeAllTimers()
+
+        XCTAssertEqual(appState.countdown.state, .idle)
+    }
+
+    func testResetClearsTheBulkPause() {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+        appState.startCountdown()
+
+        appState.pauseAllTimers()
+        appState.resetPomodoro()
+        appState.resetCountdown()
+
+        XCTAssertFalse(appState.hasBulkPausedTimers)
+    }
+
+    func testManualResumeClearsTheBulkPause() {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+
+        appState.pauseAllTimers()
+        appState.resumePomodoro()
+        appState.pausePomodoro()
+        XCTAssertFalse(appState.hasBulkPausedTimers)
+
+        // A later Resume All must not undo the user's own pause.
+        appState.resumeAllTimers()
+        XCTAssertEqual(appState.pomodoro.state, .paused)
+    }
+}
@@ -0,0 +1,24 @@
+import XCTest
+@testable import Orchestrana
+
+final class ClockChangeTests: XCTestCase {
+    func testClockChangeIsAnnouncedAndLeavesRunningTimersAlone() {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+        appState.pomodoro.adjustRemaining(bySeconds: -90)
+        appState.startCountdown()
+        let pomodoroRemaining = appState.pomodoro.remainingSeconds
+        let countdownRemaining = appState.countdown.remainingSeconds
+
+        let adjusted = expectation(forNotification: .clockAdjusted, object: appState)
+        NotificationCenter.default.post(name: .NSSystemClockDidChange, object: nil)
+        wait(for: [adjusted], timeout: 1)
+
+        XCTAssertEqual(appState.pomodoro.state, .running)
+        XCTAssertEqual(appState.pomodoro.remainingSeconds, pomodoroRemaining)
+        XCTAssertEqual(appState.countdown.state, .running)
+        XCTAssertEqual(appState.countdown.remainingSeconds, countdownRemaining)
+        appState.resetPomodoro()
+        appState.resetCountdown()
+    }
+}
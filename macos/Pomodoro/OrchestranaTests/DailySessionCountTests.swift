@@ -0,0 +1,39 @@
+import XCTest
+@testable import Orchestrana
+
+final class DailySessionCountTests: XCTestCase {
+    /// Runs four work sessions and the breaks after them, ending as the next work session starts.
+    private func runFullCycle(_ appState: AppState) {
+        appState.startPomodoro()
+        for _ in 0..<8 {
+            appState.pomodoro.adjustRemaining(bySeconds: -appState.pomodoro.remainingSeconds)
+        }
+        XCTAssertEqual(appState.pomodoro.state, .running)
+        XCTAssertEqual(appState.pomodoro.mode, .work)
+    }
+
+    func testBreaksCountAsSessionsByDefault() {
+        let appState = AppState.makeForTesting()
+        XCTAssertTrue(appState.countsBreaksAsSessions)
+
+        runFullCycle(appState)
+
+        let stats = appState.dailyStats
+        XCTAssertEqual(stats.completedSessionCount(includingBreaks: appState.countsBreaksAsSessions), 8)
+        XCTAssertEqual(stats.completedFocusSessions, 4)
+        appState.pomodoro.reset()
+    }
+
+    func testOnlyWorkSessionsCountWhenBreaksAreExcluded() {
+        let appState = AppState.makeForTesting()
+        appState.countsBreaksAsSessions = false
+
+        runFullCycle(appState)
+
+        let stats = appState.dailyStats
+        XCTAssertEqual(stats.completedSessionCount(includingBreaks: appState.countsBreaksAsSessions), 4)
+        XCTAssertEqual(stats.completedSessionCount(includingBreaks: true), 8)
+        XCTAssertEqual(stats.completedFocusSessions, 4)
+        appState.pomodoro.reset()
+    }
+}
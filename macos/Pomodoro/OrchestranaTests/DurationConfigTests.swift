@@ -0,0 +1,60 @@
+import XCTest
+@testable import Orchestrana
+
+final class DurationConfigTests: XCTestCase {
+    func testSecondsFromMinutesClampsToTheValidRange() {
+        let maximumMinutes = DurationConfig.maximumDurationSeconds / 60
+        XCTAssertEqual(DurationConfig.seconds(fromMinutes: 0), 1)
+        XCTAssertEqual(DurationConfig.seconds(fromMinutes: -5), 1)
+        XCTAssertEqual(DurationConfig.seconds(fromMinutes: 25), 1500)
+        XCTAssertEqual(DurationConfig.seconds(fromMinutes: maximumMinutes), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.seconds(fromMinutes: maximumMinutes + 1), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.seconds(fromMinutes: Int.max), DurationConfig.maximumDurationSeconds)
+    }
+
+    func testClampedDurationKeepsAtLeastOneSecondAndAtMostTheMaximum() {
+        XCTAssertEqual(DurationConfig.clampedDuration(0), 1)
+        XCTAssertEqual(DurationConfig.clampedDuration(Int.min), 1)
+        XCTAssertEqual(DurationConfig.clampedDuration(90), 90)
+        XCTAssertEqual(DurationConfig.clampedDuration(DurationConfig.maximumDurationSeconds), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.clampedDuration(DurationConfig.maximumDurationSeconds + 1), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.clampedDuration(Int.max), DurationConfig.maximumDurationSeconds)
+    }
+
+    func testInitClampsEveryDuration() {
+        let config = DurationConfig(
+            workDuration: Int.max,
+            shortBreakDuration: 0,
+            longBreakDuration: DurationConfig.maximumDurationSeconds + 1,
+            longBreakInterval: 0,
+            countdownDuration: -1
+        )
+        XCTAssertEqual(config.workDuration, DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(config.shortBreakDuration, 1)
+        XCTAssertEqual(config.longBreakDuration, DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(config.longBreakInterval, 1)
+        XCTAssertEqual(config.countdownDuration, 1)
+    }
+
+    func testClockTextAcceptsMinutesAndMinutesWithSeconds() {
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "5"), 300)
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: " 2:05 "), 125)
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "0"), 0)
+    }
+
+    func testClockTextRejectsMalformedAndNegativeEntries() {
+        XCTAssertNil(DurationConfig.seconds(fromClockText: ""))
+        XCTAssertNil(DurationConfig.seconds(fromClockText: "abc"))
+        XCTAssertNil(DurationConfig.seconds(fromClockText: "1:60"))
+        XCTAssertNil(DurationConfig.seconds(fromClockText: "1:2:3"))
+        XCTAssertNil(DurationConfig.seconds(fromClockText: "-5"))
+    }
+
+    func testHugeClockTextClampsInsteadOfOverflowing() {
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "99999999999999999"), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "99999999999999999:59"), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "\(Int.max)"), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "1440:00"), DurationConfig.maximumDurationSeconds)
+        XCTAssertEqual(DurationConfig.seconds(fromClockText: "1439:59"), DurationConfig.maximumDurationSeconds - 1)
+    }
+}
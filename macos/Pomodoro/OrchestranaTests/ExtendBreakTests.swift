@@ -0,0 +1,60 @@
+import XCTest
+@testable import Orchestrana
+
+final class ExtendBreakTests: XCTestCase {
+    func testExtendingAShortBreakAddsToItsLength() {
+        let engine = PomodoroTimerEngine()
+        engine.start()
+        engine.startBreak()
+        engine.adjustRemaining(bySeconds: -60)
+        let shortBreak = DurationConfig.standard.shortBreakDuration
+
+        engine.extendBreak(bySeconds: 120)
+
+        XCTAssertEqual(engine.state, .breakRunning)
+        XCTAssertEqual(engine.mode, .breakTime)
+        XCTAssertEqual(engine.remainingSeconds, shortBreak - 60 + 120)
+        XCTAssertEqual(engine.sessionDurationSeconds, shortBreak + 120)
+        XCTAssertEqual(engine.elapsedSeconds, 60)
+        engine.reset()
+    }
+
+    func testExtendingAPausedBreakKeepsItPaused() {
+        let engine = PomodoroTimerEngine()
+        engine.start()
+        engine.startBreak()
+        engine.pause()
+
+        engine.extendBreak(bySeconds: 60)
+
+        XCTAssertEqual(engine.state, .breakPaused)
+        XCTAssertEqual(engine.remainingSeconds, DurationConfig.standard.shortBreakDuration + 60)
+        engine.reset()
+    }
+
+    func testExtendingOutsideABreakDoesNothing() {
+        let engine = PomodoroTimerEngine()
+        engine.extendBreak(bySeconds: 60)
+        XCTAssertEqual(engine.state, .idle)
+        XCTAssertEqual(engine.remainingSeconds, DurationConfig.standard.workDuration)
+
+        engine.start()
+        engine.extendBreak(bySeconds: 60)
+        XCTAssertEqual(engine.state, .running)
+        XCTAssertEqual(engine.remainingSeconds, DurationConfig.standard.workDuration)
+        XCTAssertEqual(engine.sessionDurationSeconds, DurationConfig.standard.workDuration)
+        engine.reset()
+    }
+
+    func testNonPositiveExtensionsAreIgnored() {
+        let engine = PomodoroTimerEngine()
+        engine.start()
+        engine.startBreak()
+
+        engine.extendBreak(bySeconds: 0)
+        engine.extendBreak(bySeconds: -30)
+
+        XCTAssertEqual(engine.remainingSeconds, DurationConfig.standard.shortBreakDuration)
+        engine.reset()
+    }
+}
@@ -0,0 +1,74 @@
+import XCTest
+@testable import Orchestrana
+
+final class LongBreakIntervalTests: XCTestCase {
+    private func config(interval: Int, workDuration: Int = 25 * 60) -> DurationConfig {
+        DurationConfig(
+            workDuration: workDuration,
+            shortBreakDuration: 5 * 60,
+            longBreakDuration: 15 * 60,
+            longBreakInterval: interval,
+            countdownDuration: 10 * 60
+        )
+    }
+
+    private func finishSession(_ engine: PomodoroTimerEngine) {
+        engine.adjustRemaining(bySeconds: -engine.remainingSeconds)
+    }
+
+    /// Runs `count` work sessions and the short breaks between them, stopping in the last break.
+    private func completeWorkSessions(_ count: Int, on engine: PomodoroTimerEngine) {
+        for index in 0..<count {
+            if index > 0 {
+                finishSession(engine)
+            }
+            finishSession(engine)
+        }
+    }
+
+    func testDecreasingMidCycleGivesTheLongBreakAfterTheNextSession() {
+        let engine = PomodoroTimerEngine(durationConfig: config(interval: 4))
+        engine.start()
+        completeWorkSessions(3, on: engine)
+        XCTAssertEqual(engine.completedWorkSessions, 3)
+
+        engine.updateConfiguration(durationConfig: config(interval: 2))
+        XCTAssertEqual(engine.completedWorkSessions, 1)
+
+        finishSession(engine)
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .longBreak)
+        engine.reset()
+    }
+
+    func testIncreasingMidCycleKeepsProgress() {
+        let engine = PomodoroTimerEngine(durationConfig: config(interval: 2))
+        engine.start()
+        completeWorkSessions(1, on: engine)
+
+        engine.updateConfiguration(durationConfig: config(interval: 4))
+        XCTAssertEqual(engine.completedWorkSessions, 1)
+
+        finishSession(engine)
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .breakTime)
+        XCTAssertEqual(engine.completedWorkSessions, 2)
+
+        finishSession(engine)
+        finishSession(engine)
+        finishSession(engine)
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .longBreak)
+        engine.reset()
+    }
+
+    func testOtherChangesLeaveProgressAlone() {
+        let engine = PomodoroTimerEngine(durationConfig: config(interval: 4))
+        engine.start()
+        completeWorkSessions(3, on: engine)
+
+        engine.updateConfiguration(durationConfig: config(interval: 4, workDuration: 50 * 60))
+        XCTAssertEqual(engine.completedWorkSessions, 3)
+        engine.reset()
+    }
+}
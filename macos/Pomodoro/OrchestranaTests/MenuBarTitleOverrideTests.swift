@@ -0,0 +1,30 @@
+import XCTest
+@testable import Orchestrana
+
+final class MenuBarTitleOverrideTests: XCTestCase {
+    func testOverrideLastsUntilItExpires() {
+        let appState = AppState.makeForTesting()
+        let start = Date()
+        appState.setMenuBarTitleOverride("BUILD RUNNING", durationSeconds: 60, now: start)
+        XCTAssertEqual(appState.menuBarTitleOverride?.text, "BUILD RUNNING")
+
+        appState.armMenuBarTitleOverrideExpiry(now: start.addingTimeInterval(30))
+        XCTAssertNotNil(appState.menuBarTitleOverride)
+
+        // E.g. after waking from a sleep that outlasted the override.
+        appState.armMenuBarTitleOverrideExpiry(now: start.addingTimeInterval(61))
+        XCTAssertNil(appState.menuBarTitleOverride)
+    }
+
+    func testEmptyTextOrNoDurationClearsTheOverride() {
+        let appState = AppState.makeForTesting()
+        appState.setMenuBarTitleOverride("BUILD RUNNING", durationSeconds: 60)
+
+        appState.setMenuBarTitleOverride("  ", durationSeconds: 60)
+        XCTAssertNil(appState.menuBarTitleOverride)
+
+        appState.setMenuBarTitleOverride("BUILD RUNNING", durationSeconds: 60)
+        appState.setMenuBarTitleOverride("BUILD RUNNING", durationSeconds: 0)
+        XCTAssertNil(appState.menuBarTitleOverride)
+    }
+}
@@ -0,0 +1,59 @@
+import XCTest
+@testable import Orchestrana
+
+final class ReminderTests: XCTestCase {
+    /// Reminders are set to one minute before the end.
+    private func makeAppState(workDuration: Int = 25 * 60, countdownDuration: Int = 2 * 60) -> AppState {
+        let appState = AppState.makeForTesting(durationConfig: DurationConfig(
+            workDuration: workDuration,
+            shortBreakDuration: 5 * 60,
+            longBreakDuration: 15 * 60,
+            longBreakInterval: 4,
+            countdownDuration: countdownDuration
+        ))
+        // In-app delivery, so setting a reminder never asks for notification permission.
+        appState.notificationDeliveryStyle = .inApp
+        appState.reminderPreference = .oneMinute
+        return appState
+    }
+
+    func testPomodoroReminderFiresWhenTheTimeJumpsPastTheLeadTime() {
+        let appState = makeAppState()
+        appState.startPomodoro()
+
+        appState.pomodoro.adjustRemaining(bySeconds: 30 - appState.pomodoro.remainingSeconds)
+
+        XCTAssertTrue(appState.pomodoroReminderSent)
+        appState.resetPomodoro()
+    }
+
+    func testPomodoroReminderSkipsSessionsNoLongerThanTheLeadTime() {
+        let appState = makeAppState(workDuration: 60)
+        appState.startPomodoro()
+
+        appState.pomodoro.adjustRemaining(bySeconds: -10)
+
+        XCTAssertFalse(appState.pomodoroReminderSent)
+        appState.resetPomodoro()
+    }
+
+    func testCountdownReminderFiresWhenTheTimeJumpsPastTheLeadTime() {
+        let appState = makeAppState()
+        appState.startCountdown()
+
+        appState.setCountdownRemaining(seconds: 30)
+
+        XCTAssertTrue(appState.countdownReminderSent)
+        appState.resetCountdown()
+    }
+
+    func testCountdownReminderSkipsCountdownsNoLongerThanTheLeadTime() {
+        let appState = makeAppState(countdownDuration: 60)
+        appState.startCountdown()
+
+        appState.setCountdownRemaining(seconds: 50)
+
+        XCTAssertFalse(appState.countdownReminderSent)
+        appState.resetCountdown()
+    }
+}
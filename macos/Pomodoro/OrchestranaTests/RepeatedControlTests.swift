@@ -0,0 +1,79 @@
+import Combine
+import XCTest
+@testable import Orchestrana
+
+final class RepeatedControlTests: XCTestCase {
+    private var cancellables = Set<AnyCancellable>()
+
+    override func tearDown() {
+        cancellables.removeAll()
+        super.tearDown()
+    }
+
+    /// Counts `objectWillChange` emissions from the moment it is called.
+    private func changeCounter<Engine: ObservableObject>(for engine: Engine) -> () -> Int {
+        var count = 0
+        engine.objectWillChange
+            .sink { _ in count += 1 }
+            .store(in: &cancellables)
+        return { count }
+    }
+
+    func testPomodoroIgnoresASecondStartAndPause() {
+        let engine = PomodoroTimerEngine()
+        engine.start()
+        engine.adjustRemaining(bySeconds: -60)
+        let remaining = engine.remainingSeconds
+        var changes = changeCounter(for: engine)
+
+        engine.start()
+        XCTAssertEqual(engine.state, .running)
+        XCTAssertEqual(engine.remainingSeconds, remaining)
+        XCTAssertEqual(changes(), 0)
+
+        engine.pause()
+        XCTAssertEqual(engine.state, .paused)
+        changes = changeCounter(for: engine)
+        engine.pause()
+        XCTAssertEqual(engine.state, .paused)
+        XCTAssertEqual(engine.remainingSeconds, remaining)
+        XCTAssertEqual(changes(), 0)
+        engine.reset()
+    }
+
+    func testPomodoroIgnoresASecondPauseDuringABreak() {
+        let engine = PomodoroTimerEngine()
+        engine.start()
+        engine.startBreak()
+        engine.pause()
+        let changes = changeCounter(for: engine)
+
+        engine.pause()
+        engine.start()
+        XCTAssertEqual(engine.state, .breakPaused)
+        XCTAssertEqual(engine.mode, .breakTime)
+        XCTAssertEqual(changes(), 0)
+        engine.reset()
+    }
+
+    func testCountdownIgnoresASecondStartAndPause() {
+        let engine = CountdownTimerEngine()
+        engine.start()
+        engine.setRemaining(120)
+        var changes = changeCounter(for: engine)
+
+        engine.start()
+        XCTAssertEqual(engine.state, .running)
+        XCTAssertEqual(engine.remainingSeconds, 120)
+        XCTAssertEqual(changes(), 0)
+
+        engine.pause()
+        XCTAssertEqual(engine.state, .paused)
+        changes = changeCounter(for: engine)
+        engine.pause()
+        XCTAssertEqual(engine.state, .paused)
+        XCTAssertEqual(engine.remainingSeconds, 120)
+        XCTAssertEqual(changes(), 0)
+        engine.reset()
+    }
+}
@@ -0,0 +1,48 @@
+import XCTest
+@testable import Orchestrana
+
+final class ResetScopeTests: XCTestCase {
+    func testCycleResetReturnsToIdleWorkAndClearsThePlan() {
+        let appState = AppState.makeForTesting()
+        appState.applyPlan(title: "Write report", pomodoroCount: 3)
+        appState.startPomodoro()
+        appState.startBreak()
+
+        appState.resetPomodoro()
+
+        XCTAssertEqual(appState.pomodoro.state, .idle)
+        XCTAssertEqual(appState.pomodoro.mode, .work)
+        XCTAssertEqual(appState.pomodoro.remainingSeconds, DurationConfig.standard.workDuration)
+        XCTAssertNil(appState.currentPlanTitle)
+    }
+
+    func testSessionResetRestartsTheWorkSessionPausedAndKeepsThePlan() {
+        let appState = AppState.makeForTesting()
+        appState.pomodoroResetScope = .session
+        appState.applyPlan(title: "Write report", pomodoroCount: 3)
+        appState.startPomodoro()
+        appState.pomodoro.adjustRemaining(bySeconds: -60)
+
+        appState.resetPomodoro()
+
+        XCTAssertEqual(appState.pomodoro.state, .paused)
+        XCTAssertEqual(appState.pomodoro.remainingSeconds, DurationConfig.standard.workDuration)
+        XCTAssertEqual(appState.currentPlanTitle, "Write report")
+        appState.pomodoro.reset()
+    }
+
+    func testSessionResetKeepsABreakInItsBreak() {
+        let appState = AppState.makeForTesting()
+        appState.pomodoroResetScope = .session
+        appState.startPomodoro()
+        appState.startBreak()
+        appState.pomodoro.adjustRemaining(bySeconds: -60)
+
+        appState.resetPomodoro()
+
+        XCTAssertEqual(appState.pomodoro.state, .breakPaused)
+        XCTAssertEqual(appState.pomodoro.mode, .breakTime)
+        XCTAssertEqual(appState.pomodoro.remainingSeconds, DurationConfig.standard.shortBreakDuration)
+        appState.pomodoro.reset()
+    }
+}
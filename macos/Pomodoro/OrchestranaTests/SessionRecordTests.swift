@@ -0,0 +1,38 @@
+import XCTest
+@testable import Orchestrana
+
+final class SessionRecordTests: XCTestCase {
+    private func waitForMainQueue(_ seconds: TimeInterval = 0) {
+        let waited = expectation(description: "main queue turn")
+        DispatchQueue.main.asyncAfter(deadline: .now() + seconds) {
+            waited.fulfill()
+        }
+        wait(for: [waited], timeout: seconds + 2)
+    }
+
+    func testPauseMidSessionIsRecordedWithTheSession() throws {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+        appState.pomodoro.adjustRemaining(bySeconds: -120)
+
+        appState.pausePomodoro()
+        waitForMainQueue(1.2)
+        appState.resumePomodoro()
+        appState.pomodoro.adjustRemaining(bySeconds: -appState.pomodoro.remainingSeconds)
+        // Records are appended from a main-actor task.
+        waitForMainQueue()
+
+        let record = try XCTUnwrap(appState.sessionRecordStore.records.last)
+        XCTAssertEqual(record.sessionType, .focus)
+        XCTAssertTrue(record.completed)
+        XCTAssertEqual(record.durationSeconds, DurationConfig.standard.workDuration)
+        XCTAssertEqual(record.pausedSeconds, 1)
+        // The start time reaches back over the running time and the pause.
+        XCTAssertEqual(
+            record.endTime.timeIntervalSince(record.startTime),
+            TimeInterval(DurationConfig.standard.workDuration + 1),
+            accuracy: 0.001
+        )
+        appState.pomodoro.reset()
+    }
+}
@@ -0,0 +1,56 @@
+import XCTest
+@testable import Orchestrana
+
+final class SkipBreakTests: XCTestCase {
+    private func finishSession(_ engine: PomodoroTimerEngine) {
+        engine.adjustRemaining(bySeconds: -engine.remainingSeconds)
+    }
+
+    /// Starts a work session, runs it to the end and skips the break that follows.
+    private func workThenSkipBreak(_ engine: PomodoroTimerEngine) {
+        engine.start()
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .breakTime)
+        engine.skipBreak()
+        XCTAssertEqual(engine.state, .idle)
+    }
+
+    func testSkippedBreaksCountTowardTheLongBreakByDefault() {
+        let engine = PomodoroTimerEngine()
+        XCTAssertTrue(engine.skipCountsTowardCycle)
+        for _ in 0..<3 {
+            workThenSkipBreak(engine)
+        }
+        XCTAssertEqual(engine.completedWorkSessions, 3)
+
+        engine.start()
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .longBreak)
+        engine.reset()
+    }
+
+    func testSkippedBreaksDoNotCountWhenTurnedOff() {
+        let engine = PomodoroTimerEngine()
+        engine.skipCountsTowardCycle = false
+        for _ in 0..<3 {
+            workThenSkipBreak(engine)
+        }
+        XCTAssertEqual(engine.completedWorkSessions, 0)
+
+        engine.start()
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .breakTime)
+        XCTAssertEqual(engine.completedWorkSessions, 1)
+
+        // Breaks that run out count, so the long break arrives after three more full rounds.
+        for _ in 0..<2 {
+            finishSession(engine)
+            finishSession(engine)
+            XCTAssertEqual(engine.mode, .breakTime)
+        }
+        finishSession(engine)
+        finishSession(engine)
+        XCTAssertEqual(engine.mode, .longBreak)
+        engine.reset()
+    }
+}
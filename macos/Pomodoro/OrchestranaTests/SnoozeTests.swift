@@ -0,0 +1,66 @@
+import XCTest
+@testable import Orchestrana
+
+final class SnoozeTests: XCTestCase {
+    func testEndSnoozeResumesWhatTheSnoozePaused() {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+        appState.startCountdown()
+        appState.pauseCountdown()
+
+        appState.snooze(until: Date().addingTimeInterval(3600))
+        XCTAssertEqual(appState.pomodoro.state, .paused)
+
+        appState.endSnooze()
+        XCTAssertEqual(appState.pomodoro.state, .running)
+        XCTAssertEqual(appState.countdown.state, .paused)
+        appState.resetPomodoro()
+        appState.resetCountdown()
+    }
+
+    func testResumeAllDuringSnoozeDoesNotForgetWhatTheSnoozePaused() {
+        let appState = AppState.makeForTesting()
+        appState.startPomodoro()
+
+        appState.snooze(until: Date().addingTimeInterval(3600))
+        appState.pauseAllTimers()
+        appState.resumeAllTimers()
+        appState.endSnooze()
+
+        XCTAssertEqual(appState.pomodoro.state, .running)
+        appState.resetPomodoro()
+    }
+
+    func testTimerStartedDuringSnoozeWaitsForItToEnd() {
+        let appState = AppState.makeForTesting()
+        appState.snooze(until: Date().addingTimeInterval(3600))
+
+        appState.startCountdown()
+        let held = expectation(description: "countdown paused again")
+        DispatchQueue.main.async {
+            held.fulfill()
+        }
+        wait(for: [held], timeout: 1)
+        XCTAssertEqual(appState.countdown.state, .paused)
+
+        appState.endSnooze()
+        XCTAssertEqual(appState.countdown.state, .running)
+        appState.resetCountdown()
+    }
+
+    func testSnoozeThatRanOutDuringSleepEndsWhenRechecked() {
+        let appState = AppState.makeForTesting()
+        let start = Date()
+        appState.startPomodoro()
+        appState.snooze(until: start.addingTimeInterval(600), now: start)
+
+        appState.armSnoozeEnd(now: start.addingTimeInterval(300))
+        XCTAssertNotNil(appState.snoozedUntil)
+        XCTAssertEqual(appState.pomodoro.state, .paused)
+
+        appState.armSnoozeEnd(now: start.addingTimeInterval(601))
+        XCTAssertNil(appState.snoozedUntil)
+        XCTAssertEqual(appState.pomodoro.state, .running)
+        appState.resetPomodoro()
+    }
+}
@@ -34,6 +34,13 @@ final class AmbientNoiseEngine {
         var windHighL: Float
         var windHighR: Float
         var rainPhase: Float
+        /// Sound being faded out while `type` fades in. It keeps its own brown accumulator because
+        /// brown and wind would otherwise both advance `brownL`/`brownR` each frame.
+        var fadingOutType: NoiseType
+        var fadeBrownL: Float
+        var fadeBrownR: Float
+        var fadeTotalFrames: Int
+        var fadeRemainingFrames: Int
     }
 
     private let engine = AVAudioEngine()
@@ -50,6 +57,7 @@ final class AmbientNoiseEngine {
 
             let volume = state.volume
             let type = state.type
+            let fadeTotalFrames = Float(max(state.fadeTotalFrames, 1))
 
             if type == .off || volume <= 0 {
                 for buffer in bufferList {
@@ -68,7 +76,7 @@ final class AmbientNoiseEngine {
                 let whiteL = Self.nextWhite(seed: &state.randomSeedL)
                 let whiteR = Self.nextWhite(seed: &state.randomSeedR)
 
-                let sampleL = self.renderSample(
+                var sampleL = self.renderSample(
                     type: type,
                     white: whiteL,
                     brownState: &state.brownL,
@@ -79,7 +87,7 @@ final class AmbientNoiseEngine {
                     rainPhase: &state.rainPhase
                 )
 
-                let sampleR = self.renderSample(
+                var sampleR = self.renderSample(
                     type: type,
                     white: whiteR,
                     brownState: &state.brownR,
@@ -90,6 +98,36 @@ final class AmbientNoiseEngine {
                     rainPhase: &state.rainPhase
                 )
 
+                if state.fadeRemainingFrames > 0 {
+                    // Equal-power crossfade so the overall level doesn't dip mid-fade.
+                    let progress = 1 - Float(state.fadeRemainingFrames) / fadeTotalFrames
+                    let fadeIn = sin(progress * Float.pi / 2)
+                    let fadeOut = cos(progress * Float.pi / 2)
+                    let outgoingL = self.renderSample(
+                        type: state.fadingOutType,
+                        white: whiteL,
+                        brownState: &state.fadeBrownL,
+                        whiteLpf: &state.whiteLpfL,
+                        rainLpf: &state.rainLpfL,
+                        windLow: &state.windLowL,
+                        windHigh: &state.windHighL,
+                        rainPhase: &state.rainPhase
+                    )
+                    let outgoingR = self.renderSample(
+                        type: state.fadingOutType,
+                        white: whiteR,
+                        brownState: &state.fadeBrownR,
+                        whiteLpf: &state.whiteLpfR,
+                        rainLpf: &state.rainLpfR,
+                        windLow: &state.windLowR,
+                        windHigh: &state.windHighR,
+                        rainPhase: &state.rainPhase
+                    )
+                    sampleL = sampleL * fadeIn + outgoingL * fadeOut
+                    sampleR = sampleR * fadeIn + outgoingR * fadeOut
+                    state.fadeRemainingFrames -= 1
+                }
+
                 ptrL[frame] = Self.clamp(sampleL * volume)
                 ptrR[frame] = Self.clamp(sampleR * volume)
             }
@@ -108,6 +146,12 @@ final class AmbientNoiseEngine {
             self.state.windHighL = state.windHighL
             self.state.windHighR = state.windHighR
             self.state.rainPhase = state.rainPhase
+            // A fade started while this buffer rendered replaces the one rendered here.
+            if self.state.fadeTotalFrames == state.fadeTotalFrames, self.state.fadingOutType == state.fadingOutType {
+                self.state.fadeBrownL = state.fadeBrownL
+                self.state.fadeBrownR = state.fadeBrownR
+                self.state.fadeRemainingFrames = state.fadeRemainingFrames
+            }
             os_unfair_lock_unlock(&self.stateLock)
 
             return noErr
@@ -149,7 +193,12 @@ final class AmbientNoiseEngine {
             windLowR: 0,
             windHighL: 0,
             windHighR: 0,
-            rainPhase: 0
+            rainPhase: 0,
+            fadingOutType: .off,
+            fadeBrownL: 0,
+            fadeBrownR: 0,
+            fadeTotalFrames: 0,
+            fadeRemainingFrames: 0
         )
 
         audioQueue.async { [weak self] in
@@ -157,10 +206,13 @@ final class AmbientNoiseEngine {
         }
     }
 
-    func play(type: NoiseType) {
+    /// Switches to `type`. When another sound is already playing and `fadeDuration` is positive, the
+    /// two crossfade over that duration instead of cutting over.
+    func play(type: NoiseType, fadeDuration: TimeInterval = 0) {
+        let fadeFrames = Int(max(0, fadeDuration) * sampleRate)
         audioQueue.async { [weak self] in
             guard let self else { return }
-            self.updateNoiseType(type)
+            self.updateNoiseType(type, fadeFrames: self.engine.isRunning ? fadeFrames : 0)
             self.startEngineIfNeeded(for: type)
         }
     }
@@ -204,8 +256,17 @@ final class AmbientNoiseEngine {
         }
     }
 
-    private func updateNoiseType(_ type: NoiseType) {
+    private func updateNoiseType(_ type: NoiseType, fadeFrames: Int = 0) {
         os_unfair_lock_lock(&stateLock)
+        if fadeFrames > 0, state.type != .off, state.type != type {
+            state.fadingOutType = state.type
+            state.fadeBrownL = state.brownL
+            state.fadeBrownR = state.brownR
+            state.fadeTotalFrames = fadeFrames
+            state.fadeRemainingFrames = fadeFrames
+        } else {
+            state.fadeRemainingFrames = 0
+        }
         state.type = type
         os_unfair_lock_unlock(&stateLock)
     }
@@ -0,0 +1,38 @@
+import Foundation
+
+/// A file the app keeps user data in, so it can be found, backed up or deleted outside the app.
+struct AppDataLocation: Identifiable {
+    enum Kind: String, CaseIterable {
+        /// The preferences plist. Today's stats and timer settings are stored here too.
+        case settings
+        case sessionHistory
+        case analyticsCache
+    }
+
+    let kind: Kind
+    let url: URL
+    /// `nil` when the file hasn't been written yet or can't be read.
+    let sizeBytes: Int64?
+
+    var id: Kind { kind }
+
+    /// Resolves each location from the same paths the stores write to.
+    @MainActor
+    static func all(fileManager: FileManager = .default) -> [AppDataLocation] {
+        var urls: [(Kind, URL)] = [
+            (.sessionHistory, SessionRecordStore.shared.fileURL),
+            (.analyticsCache, ProductivityAnalyticsStore.shared.fileURL)
+        ]
+        if let library = fileManager.urls(for: .libraryDirectory, in: .userDomainMask).first,
+           let bundleIdentifier = Bundle.main.bundleIdentifier {
+            let plist = library
+                .appendingPathComponent("Preferences", isDirectory: true)
+                .appendingPathComponent("\(bundleIdentifier).plist")
+            urls.insert((.settings, plist), at: 0)
+        }
+        return urls.map { kind, url in
+            let size = (try? fileManager.attributesOfItem(atPath: url.path)[.size] as? NSNumber)?.int64Value
+            return AppDataLocation(kind: kind, url: url, sizeBytes: size)
+        }
+    }
+}
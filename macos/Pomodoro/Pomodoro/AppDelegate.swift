@@ -12,7 +12,18 @@ import FirebaseCore
 final class AppDelegate: NSObject, NSApplicationDelegate {
     private var appStateConfigured = false
     private var menuBarController: MenuBarController?
+    private var breakOverlayController: BreakOverlayController?
+    private var timerStateMirror: TimerStateMirror?
+    private var focusSoundRotation: FocusSoundRotationController?
+    private var focusSoundUsage: FocusSoundUsageTracker?
+    private var countdownFocusSoundMute: CountdownFocusSoundMuteController?
     private var openMainWindowScene: (() -> Void)?
+    private var timerDidStartObserver: NSObjectProtocol?
+    private var snoozeDidStartObserver: NSObjectProtocol?
+    private var snoozeDidEndObserver: NSObjectProtocol?
+    /// Set when a snooze hid the main window, so the window comes back when the snooze ends.
+    private var snoozeHidMainWindow = false
+    private var autoStartLimitObserver: NSObjectProtocol?
 
     var appState: AppState? {
         didSet {
@@ -31,19 +42,44 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
         }
     }
     func applicationShouldTerminateAfterLastWindowClosed(_ sender: NSApplication) -> Bool {
-        false
+        // By default closing the window leaves the timers running in the menu bar.
+        appState?.quitsWhenLastWindowClosed ?? false
     }
 
     func applicationWillTerminate(_ notification: Notification) {
+        // A second copy quits right after launch; its state is stale next to the running one's.
+        guard InstanceLock.isPrimary else { return }
+        appState?.flushPersistedState()
+        appState?.releaseDisplaySleepAssertion()
         menuBarController?.shutdown()
     }
 
+    func applicationDidResignActive(_ notification: Notification) {
+        // Losing focus is the last reliable moment before an ungraceful exit such as a force quit.
+        guard InstanceLock.isPrimary else { return }
+        appState?.flushPersistedState()
+    }
+
     func applicationDidFinishLaunching(_ notification: Notification) {
         configureFirebase()
     }
 
     func application(_ application: NSApplication, open urls: [URL]) {
-        AuthManager.shared.handleOpenURLs(urls)
+        let commandURLs = urls.filter { $0.scheme == Self.commandURLScheme }
+        commandURLs.forEach(handleCommandURL)
+        AuthManager.shared.handleOpenURLs(urls.filter { $0.scheme != Self.commandURLScheme })
+    }
+
+    private static let commandURLScheme = "orchestrana"
+
+    /// `orchestrana://menu-bar-title?text=BUILD%20RUNNING&seconds=300` shows a custom status item
+    /// title for `seconds` (default 60), e.g. from a script; no `text` clears it.
+    private func handleCommandURL(_ url: URL) {
+        guard let appState, url.host == "menu-bar-title" else { return }
+        let queryItems = URLComponents(url: url, resolvingAgainstBaseURL: false)?.queryItems ?? []
+        let text = queryItems.first { $0.name == "text" }?.value ?? ""
+        let seconds = queryItems.first { $0.name == "seconds" }?.value.flatMap { Int($0) } ?? 60
+        appState.setMenuBarTitleOverride(text, durationSeconds: seconds)
     }
 
     func applicationShouldHandleReopen(_ sender: NSApplication, hasVisibleWindows flag: Bool) -> Bool {
@@ -58,6 +94,14 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
         openMainWindowScene?()
     }
 
+    private func activatePrimaryInstance() {
+        guard let bundleIdentifier = Bundle.main.bundleIdentifier else { return }
+        let currentProcess = ProcessInfo.processInfo.processIdentifier
+        NSRunningApplication.runningApplications(withBundleIdentifier: bundleIdentifier)
+            .first { $0.processIdentifier != currentProcess }?
+            .activate()
+    }
+
     private func quitApp() {
         NSApplication.shared.terminate(nil)
     }
@@ -66,6 +110,12 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
         guard !appStateConfigured else { return }
         guard let appState, let musicController else { return }
         appStateConfigured = true
+        guard InstanceLock.isPrimary else {
+            // Another copy owns the menu bar item and media controls; send the user there instead.
+            activatePrimaryInstance()
+            quitApp()
+            return
+        }
         menuBarController = MenuBarController(
             appState: appState,
             musicController: musicController,
@@ -76,6 +126,69 @@ final class AppDelegate: NSObject, NSApplicationDelegate {
                 self?.quitApp()
             }
         )
+        breakOverlayController = BreakOverlayController(appState: appState)
+        timerStateMirror = TimerStateMirror(appState: appState)
+        focusSoundRotation = FocusSoundRotationController(appState: appState, musicController: musicController)
+        focusSoundUsage = FocusSoundUsageTracker(appState: appState, musicController: musicController)
+        countdownFocusSoundMute = CountdownFocusSoundMuteController(appState: appState, musicController: musicController)
+        timerDidStartObserver = NotificationCenter.default.addObserver(
+            forName: .timerDidStart,
+            object: appState,
+            queue: .main
+        ) { [weak self] _ in
+            MainActor.assumeIsolated {
+                self?.hideMainWindowIfNeeded()
+            }
+        }
+        snoozeDidStartObserver = NotificationCenter.default.addObserver(
+            forName: .snoozeDidStart,
+            object: appState,
+            queue: .main
+        ) { [weak self] notification in
+            let hidesWindow = notification.userInfo?[AppState.snoozeHidesWindowKey] as? Bool ?? false
+            MainActor.assumeIsolated {
+                guard let self, hidesWindow else { return }
+                self.snoozeHidMainWindow = self.hideMainWindow() || self.snoozeHidMainWindow
+            }
+        }
+        snoozeDidEndObserver = NotificationCenter.default.addObserver(
+            forName: .snoozeDidEnd,
+            object: appState,
+            queue: .main
+        ) { [weak self] _ in
+            MainActor.assumeIsolated {
+                guard let self, self.snoozeHidMainWindow else { return }
+                self.snoozeHidMainWindow = false
+                self.openMainWindow()
+            }
+        }
+        autoStartLimitObserver = NotificationCenter.default.addObserver(
+            forName: .autoStartLimitReached,
+            object: appState,
+            queue: .main
+        ) { [weak self] _ in
+            MainActor.assumeIsolated {
+                self?.appState?.announceAutoStartLimitReached()
+            }
+        }
+    }
+
+    private func hideMainWindowIfNeeded() {
+        guard appState?.hidesWindowOnStart == true else { return }
+        hideMainWindow()
+    }
+
+    /// Returns whether a visible main window was hidden.
+    @discardableResult
+    private func hideMainWindow() -> Bool {
+        var hidVisibleWindow = false
+        // SwiftUI derives scene window identifiers from the scene ID.
+        for window in NSApplication.shared.windows
+        where window.identifier?.rawValue.hasPrefix(OrchestranaApp.mainWindowID) == true {
+            hidVisibleWindow = hidVisibleWindow || window.isVisible
+            window.orderOut(nil)
+        }
+        return hidVisibleWindow
     }
 
     private func configureFirebase() {
@@ -5,6 +5,7 @@
 //  Created by Zhengyang Hu on 1/15/26.
 //
 
+import AppKit
 import Combine
 import SwiftUI
 import UserNotifications
@@ -15,6 +16,11 @@ final class AppState: ObservableObject {
     let countdown: CountdownTimerEngine
     let ambientNoiseEngine: AmbientNoiseEngine
     let nowPlayingRouter: NowPlayingRouter
+    /// Where finished sessions are logged; `.shared` outside tests.
+    let sessionRecordStore: SessionRecordStore
+    let analyticsStore: ProductivityAnalyticsStore
+    /// Focus sound preferences are reset, exported and imported along with the timer settings.
+    weak var musicController: MusicController?
 
     @Published var durationConfig: DurationConfig {
         didSet {
@@ -35,6 +41,10 @@ final class AppState: ObservableObject {
     @Published private(set) var currentPlanPomodoros: Int?
     @Published private(set) var currentPlanPresetID: String?
     @Published private(set) var dailyStats: DailyStats
+    @Published private(set) var focusStreak: FocusStreak
+    /// Work sessions completed since the app launched, separate from the persisted history.
+    @Published private(set) var completedFocusSessionsThisLaunch = 0
+    let launchedAt = Date()
     @Published var notificationPreference: NotificationPreference {
         didSet {
             saveNotificationPreference()
@@ -53,6 +63,236 @@ final class AppState: ObservableObject {
             requestNotificationAuthorizationIfNeeded()
         }
     }
+    /// Text shown beside the tomato in the menu bar while no timer is active.
+    /// `nil` falls back to the localized "Ready" label; an empty string shows only the glyph.
+    @Published var menuBarIdleTitle: String? {
+        didSet {
+            saveMenuBarIdleTitle()
+        }
+    }
+    /// Temporarily replaces the status item title. Not persisted; see `setMenuBarTitleOverride`.
+    @Published private(set) var menuBarTitleOverride: MenuBarTitleOverride?
+    /// Per-mode symbols in the status item title.
+    @Published var menuBarGlyphs: MenuBarGlyphs {
+        didSet {
+            userDefaults.set(menuBarGlyphs.dictionary, forKey: DefaultsKey.menuBarGlyphs)
+        }
+    }
+    /// Audio file played when a timer completes. `nil` keeps the default notification sound.
+    @Published private(set) var completionSoundPath: String?
+    /// Thread and request identifier for system notifications, so each alert replaces the previous
+    /// one in Notification Center. `nil` keeps every alert separate.
+    @Published var notificationGroup: String? {
+        didSet {
+            if let notificationGroup {
+                userDefaults.set(notificationGroup, forKey: DefaultsKey.notificationGroup)
+            } else {
+                userDefaults.removeObject(forKey: DefaultsKey.notificationGroup)
+            }
+        }
+    }
+    /// File that `TimerStateMirror` keeps up to date with the timers' state. `nil` turns mirroring off.
+    @Published var stateMirrorPath: String? {
+        didSet {
+            if let stateMirrorPath {
+                userDefaults.set(stateMirrorPath, forKey: DefaultsKey.stateMirrorPath)
+            } else {
+                userDefaults.removeObject(forKey: DefaultsKey.stateMirrorPath)
+            }
+        }
+    }
+    /// Body for Pomodoro completion notifications. `nil` uses the built-in localized text.
+    @Published private(set) var notificationBodyTemplate: NotificationTemplate?
+    /// The main window's selected tab, mirrored so the menu bar can reflect in-app navigation.
+    /// Starts at `launchSidebarItem`, and a reopened window picks up where it left off.
+    @Published var activeSidebarItem: MainWindowView.SidebarItem
+    /// The tab the main window shows when the app launches.
+    @Published var launchSidebarItem: MainWindowView.SidebarItem {
+        didSet {
+            userDefaults.set(launchSidebarItem.rawValue, forKey: DefaultsKey.launchSidebarItem)
+        }
+    }
+    /// Quits the app when its last window closes instead of leaving it running in the menu bar.
+    @Published var quitsWhenLastWindowClosed: Bool {
+        didSet {
+            userDefaults.set(quitsWhenLastWindowClosed, forKey: DefaultsKey.quitsWhenLastWindowClosed)
+        }
+    }
+    /// Hides the main window after a timer is started from the app; the menu bar keeps control.
+    @Published var hidesWindowOnStart: Bool {
+        didSet {
+            userDefaults.set(hidesWindowOnStart, forKey: DefaultsKey.hidesWindowOnStart)
+        }
+    }
+    /// When the app quits on its own to end the workday. Only today's time is kept: it's cleared
+    /// once it fires, and dropped on launch if it has passed.
+    @Published private(set) var scheduledQuitDate: Date?
+    /// While set, running timers stay paused and notifications and auto-start are held; see
+    /// `snooze(until:hidesWindow:)`. Not persisted.
+    @Published private(set) var snoozedUntil: Date?
+    /// Keeps the display from sleeping while a work session runs. Breaks and pauses let it sleep.
+    @Published var preventsDisplaySleepDuringWork: Bool {
+        didSet {
+            userDefaults.set(preventsDisplaySleepDuringWork, forKey: DefaultsKey.preventsDisplaySleepDuringWork)
+            updateDisplaySleepAssertion()
+        }
+    }
+    /// Pauses a running Pomodoro when the screen locks and resumes it on unlock.
+    @Published var pausesOnScreenLock: Bool {
+        didSet {
+            userDefaults.set(pausesOnScreenLock, forKey: DefaultsKey.pausesOnScreenLock)
+        }
+    }
+    /// True while the Pomodoro is paused because the screen locked, until it unlocks.
+    @Published private(set) var isPomodoroPausedByLock = false
+    /// Covers a display with a full-screen reminder while a break runs; see `BreakOverlayController`.
+    @Published var showsBreakOverlay: Bool {
+        didSet {
+            userDefaults.set(showsBreakOverlay, forKey: DefaultsKey.showsBreakOverlay)
+        }
+    }
+    /// Display that shows the break reminder. Nil, or a display that's no longer connected, means the primary display.
+    @Published var breakOverlayDisplayID: CGDirectDisplayID? {
+        didSet {
+            if let breakOverlayDisplayID {
+                userDefaults.set(breakOverlayDisplayID, forKey: DefaultsKey.breakOverlayDisplayID)
+            } else {
+                userDefaults.removeObject(forKey: DefaultsKey.breakOverlayDisplayID)
+            }
+        }
+    }
+    /// Hides the status item entirely (kiosk/presentation use). Timers keep running.
+    @Published var isMenuBarItemEnabled: Bool {
+        didSet {
+            userDefaults.set(isMenuBarItemEnabled, forKey: DefaultsKey.menuBarItemEnabled)
+        }
+    }
+    /// Focus seconds per day after which the next work session no longer auto-starts. 0 disables the cap.
+    @Published var dailyFocusCapSeconds: Int {
+        didSet {
+            userDefaults.set(dailyFocusCapSeconds, forKey: DefaultsKey.dailyFocusCapSeconds)
+        }
+    }
+    @Published private(set) var dailyFocusCapOverrideDay: Date?
+    /// Work sessions that may start automatically in a row before the Pomodoro stops at idle and
+    /// waits for the user. Any manual start, pause, resume, skip or reset restarts the count. 0 is unlimited.
+    @Published var maxConsecutiveAutoStarts: Int {
+        didSet {
+            userDefaults.set(maxConsecutiveAutoStarts, forKey: DefaultsKey.maxConsecutiveAutoStarts)
+        }
+    }
+    /// Outside these hours a finished break returns to idle instead of starting work. Nil allows any time.
+    @Published var workHours: WorkHours? {
+        didSet {
+            if let workHours {
+                userDefaults.set(workHours.dictionary, forKey: DefaultsKey.workHours)
+            } else {
+                userDefaults.removeObject(forKey: DefaultsKey.workHours)
+            }
+        }
+    }
+    /// Days of session history to keep, pruned on launch. 0 keeps everything.
+    @Published var historyRetentionDays: Int {
+        didSet {
+            userDefaults.set(historyRetentionDays, forKey: DefaultsKey.historyRetentionDays)
+        }
+    }
+    /// Focus seconds to aim for each week. 0 disables the weekly goal.
+    @Published var weeklyFocusGoalSeconds: Int {
+        didSet {
+            userDefaults.set(weeklyFocusGoalSeconds, forKey: DefaultsKey.weeklyFocusGoalSeconds)
+        }
+    }
+    /// First day of the week for the weekly goal, in `Calendar` numbering (1 is Sunday, 2 is Monday).
+    @Published var weekStartDay: Int {
+        didSet {
+            userDefaults.set(weekStartDay, forKey: DefaultsKey.weekStartDay)
+        }
+    }
+    /// Completed work sessions per day that count as the day's goal. 0 disables the goal.
+    @Published var dailySessionGoal: Int {
+        didSet {
+            userDefaults.set(dailySessionGoal, forKey: DefaultsKey.dailySessionGoal)
+        }
+    }
+    @Published var stopsAutoStartAtDailyGoal: Bool {
+        didSet {
+            userDefaults.set(stopsAutoStartAtDailyGoal, forKey: DefaultsKey.dailyGoalStopsAutoStart)
+        }
+    }
+    /// Whether completed breaks add to the sessions shown in the daily summary. Work-session counts
+    /// such as the daily goal always ignore breaks.
+    @Published var countsBreaksAsSessions: Bool {
+        didSet {
+            userDefaults.set(countsBreaksAsSessions, forKey: DefaultsKey.countsBreaksAsSessions)
+        }
+    }
+    /// See `PomodoroTimerEngine.autoStartDelayWorkSeconds`.
+    @Published var autoStartDelayWorkSeconds: Int {
+        didSet {
+            pomodoro.autoStartDelayWorkSeconds = autoStartDelayWorkSeconds
+            userDefaults.set(autoStartDelayWorkSeconds, forKey: DefaultsKey.autoStartDelayWorkSeconds)
+        }
+    }
+    /// See `PomodoroTimerEngine.autoStartDelayBreakSeconds`.
+    @Published var autoStartDelayBreakSeconds: Int {
+        didSet {
+            pomodoro.autoStartDelayBreakSeconds = autoStartDelayBreakSeconds
+            userDefaults.set(autoStartDelayBreakSeconds, forKey: DefaultsKey.autoStartDelayBreakSeconds)
+        }
+    }
+    /// See `PomodoroTimerEngine.shortBreakGrowthSeconds`.
+    @Published var shortBreakGrowthSeconds: Int {
+        didSet {
+            pomodoro.shortBreakGrowthSeconds = shortBreakGrowthSeconds
+            userDefaults.set(shortBreakGrowthSeconds, forKey: DefaultsKey.shortBreakGrowthSeconds)
+        }
+    }
+    /// See `PomodoroTimerEngine.minimumBreakSeconds`.
+    @Published var minimumBreakSeconds: Int {
+        didSet {
+            pomodoro.minimumBreakSeconds = minimumBreakSeconds
+            userDefaults.set(minimumBreakSeconds, forKey: DefaultsKey.minimumBreakSeconds)
+        }
+    }
+    /// See `PomodoroTimerEngine.skipCountsTowardCycle`.
+    @Published var skipCountsTowardCycle: Bool {
+        didSet {
+            pomodoro.skipCountsTowardCycle = skipCountsTowardCycle
+            userDefaults.set(skipCountsTowardCycle, forKey: DefaultsKey.skipCountsTowardCycle)
+        }
+    }
+    @Published var menuBarLayout: MenuBarLayout {
+        didSet {
+            userDefaults.set(menuBarLayout.rawValue, forKey: DefaultsKey.menuBarLayout)
+        }
+    }
+    @Published var menuBarPrimaryTimer: MenuBarPrimaryTimer {
+        didSet {
+            userDefaults.set(menuBarPrimaryTimer.rawValue, forKey: DefaultsKey.menuBarPrimaryTimer)
+        }
+    }
+    @Published var timerDisplayMode: TimerDisplayMode {
+        didSet {
+            userDefaults.set(timerDisplayMode.rawValue, forKey: DefaultsKey.timerDisplayMode)
+        }
+    }
+    /// What `resetPomodoro()` clears.
+    @Published var pomodoroResetScope: PomodoroResetScope {
+        didSet {
+            userDefaults.set(pomodoroResetScope.rawValue, forKey: DefaultsKey.pomodoroResetScope)
+        }
+    }
+    @Published var menuBarWidth: MenuBarWidth {
+        didSet {
+            userDefaults.set(menuBarWidth.rawValue, forKey: DefaultsKey.menuBarWidth)
+        }
+    }
+    @Published var menuBarModeIndicator: MenuBarModeIndicator {
+        didSet {
+            userDefaults.set(menuBarModeIndicator.rawValue, forKey: DefaultsKey.menuBarModeIndicator)
+        }
+    }
     // UI-only flag: Flow Mode is a presentation context, not a data/pomodoro state.
     // This is intentionally not persisted and must not trigger timer resets.
     @Published var isInFlowMode: Bool = false
@@ -64,17 +304,34 @@ final class AppState: ObservableObject {
     private let notificationCenter: UNUserNotificationCenter
     private var pomodoroDidReachZero = false
     private var countdownDidReachZero = false
-    private var pomodoroReminderSent = false
-    private var countdownReminderSent = false
+    /// Whether the current session or countdown already had its ending-soon reminder.
+    private(set) var pomodoroReminderSent = false
+    private(set) var countdownReminderSent = false
     private var lastPomodoroState: TimerState?
+    private let displaySleepAssertion = DisplaySleepAssertion()
+    private var scheduledQuitTimers: [Timer] = []
+    private var menuBarTitleOverrideTimer: Timer?
+    private var snoozeTimer: Timer?
+    static let snoozeHidesWindowKey = "hidesWindow"
+    private static let scheduledQuitWarningSeconds: TimeInterval = 60
+    /// Monotonic, so a wall-clock change can't hold notifications back or let a burst through.
+    private var lastCompletionNotificationAt: TimeInterval?
+    private var heldCompletionNotifications: [(title: String, body: String)] = []
+    private static let completionNotificationInterval: TimeInterval = 2
     private var lastCountdownState: TimerState?
     private var lastBreakMode: PomodoroTimerEngine.CurrentMode?
     private var currentFocusDurationSeconds: Int?
     private var currentBreakDurationSeconds: Int?
+    private var pomodoroWasRunningBeforeBulkPause = false
+    private var countdownWasRunningBeforeBulkPause = false
+    /// Timers a snooze paused, or that were started and held during it, for `endSnooze()` to resume.
+    /// Kept apart from the bulk pause flags so Pause All and Resume All can't change what it resumes.
+    private var snoozePausedTimers: (pomodoro: Bool, countdown: Bool) = (false, false)
     private var hasRequestedNotificationAuthorization: Bool = false
     private let eventStore = SharedEventStore.shared.eventStore
     private var pendingExecutionQueue: [PlanExecutionEntry] = []
     private var activeExecutionEntry: PlanExecutionEntry?
+    private let completionSoundPlayer = CompletionSoundPlayer()
 
     struct PlanExecutionEntry: Identifiable, Equatable {
         let id: UUID
@@ -90,17 +347,22 @@ final class AppState: ObservableObject {
         countdown: CountdownTimerEngine,
         durationConfig: DurationConfig,
         userDefaults: UserDefaults,
-        ambientNoiseEngine: AmbientNoiseEngine
+        ambientNoiseEngine: AmbientNoiseEngine,
+        sessionRecordStore: SessionRecordStore,
+        analyticsStore: ProductivityAnalyticsStore
     ) {
         self.pomodoro = pomodoro
         self.countdown = countdown
         self.ambientNoiseEngine = ambientNoiseEngine
+        self.sessionRecordStore = sessionRecordStore
+        self.analyticsStore = analyticsStore
         self.nowPlayingRouter = NowPlayingRouter(startPolling: false)
         self.durationConfig = durationConfig
         self.presetSelection = Self.loadPresetSelection(from: userDefaults, durationConfig: durationConfig)
         self.pomodoroMode = pomodoro.mode
         self.pomodoroCurrentMode = pomodoro.currentMode
         self.dailyStats = Self.loadDailyStats(from: userDefaults)
+        self.focusStreak = Self.loadFocusStreak(from: userDefaults)
         self.userDefaults = userDefaults
         self.notificationCenter = UNUserNotificationCenter.current()
         self.notificationPreference = NotificationPreference(
@@ -112,6 +374,69 @@ final class AppState: ObservableObject {
         self.reminderPreference = ReminderPreference(
             rawValue: userDefaults.string(forKey: DefaultsKey.reminderPreference) ?? ""
         ) ?? .off
+        self.menuBarIdleTitle = userDefaults.string(forKey: DefaultsKey.menuBarIdleTitle)
+        self.notificationGroup = userDefaults.string(forKey: DefaultsKey.notificationGroup)
+        self.stateMirrorPath = userDefaults.string(forKey: DefaultsKey.stateMirrorPath)
+        self.menuBarGlyphs = userDefaults.dictionary(forKey: DefaultsKey.menuBarGlyphs)
+            .flatMap(MenuBarGlyphs.init(dictionary:)) ?? .standard
+        self.completionSoundPath = userDefaults.data(forKey: DefaultsKey.completionSoundBookmark)
+            .flatMap(CompletionSoundPlayer.resolveBookmark)?.path
+            ?? userDefaults.string(forKey: DefaultsKey.completionSoundPath)
+        self.notificationBodyTemplate = userDefaults.string(forKey: DefaultsKey.notificationBodyTemplate)
+            .flatMap { try? NotificationTemplate($0) }
+        pomodoro.loadProgram(userDefaults.string(forKey: DefaultsKey.intervalProgram).flatMap { try? IntervalProgram($0) })
+        self.dailyFocusCapSeconds = max(0, userDefaults.integer(forKey: DefaultsKey.dailyFocusCapSeconds))
+        self.dailyFocusCapOverrideDay = userDefaults.object(forKey: DefaultsKey.dailyFocusCapOverrideDay) as? Date
+        self.dailySessionGoal = max(0, userDefaults.integer(forKey: DefaultsKey.dailySessionGoal))
+        self.maxConsecutiveAutoStarts = max(0, userDefaults.integer(forKey: DefaultsKey.maxConsecutiveAutoStarts))
+        self.workHours = userDefaults.dictionary(forKey: DefaultsKey.workHours).flatMap(WorkHours.init(dictionary:))
+        self.weeklyFocusGoalSeconds = max(0, userDefaults.integer(forKey: DefaultsKey.weeklyFocusGoalSeconds))
+        self.historyRetentionDays = max(0, userDefaults.integer(forKey: DefaultsKey.historyRetentionDays))
+        let storedWeekStartDay = userDefaults.integer(forKey: DefaultsKey.weekStartDay)
+        self.weekStartDay = (1...7).contains(storedWeekStartDay) ? storedWeekStartDay : 2
+        self.stopsAutoStartAtDailyGoal = userDefaults.bool(forKey: DefaultsKey.dailyGoalStopsAutoStart)
+        self.countsBreaksAsSessions = userDefaults.object(forKey: DefaultsKey.countsBreaksAsSessions) as? Bool ?? true
+        self.skipCountsTowardCycle = userDefaults.object(forKey: DefaultsKey.skipCountsTowardCycle) as? Bool ?? true
+        self.autoStartDelayWorkSeconds = max(0, userDefaults.integer(forKey: DefaultsKey.autoStartDelayWorkSeconds))
+        self.autoStartDelayBreakSeconds = max(0, userDefaults.integer(forKey: DefaultsKey.autoStartDelayBreakSeconds))
+        self.minimumBreakSeconds = max(0, userDefaults.integer(forKey: DefaultsKey.minimumBreakSeconds))
+        self.shortBreakGrowthSeconds = max(0, userDefaults.integer(forKey: DefaultsKey.shortBreakGrowthSeconds))
+        self.hidesWindowOnStart = userDefaults.bool(forKey: DefaultsKey.hidesWindowOnStart)
+        self.quitsWhenLastWindowClosed = userDefaults.bool(forKey: DefaultsKey.quitsWhenLastWindowClosed)
+        if let storedQuitDate = userDefaults.object(forKey: DefaultsKey.scheduledQuitDate) as? Date,
+           storedQuitDate > Date(), Calendar.current.isDateInToday(storedQuitDate) {
+            self.scheduledQuitDate = storedQuitDate
+        } else {
+            userDefaults.removeObject(forKey: DefaultsKey.scheduledQuitDate)
+        }
+        let storedLaunchItem = userDefaults.string(forKey: DefaultsKey.launchSidebarItem)
+            .flatMap(MainWindowView.SidebarItem.init(rawValue:))
+            .flatMap { MainWindowView.SidebarItem.visibleItems.contains($0) ? $0 : nil }
+        self.launchSidebarItem = storedLaunchItem ?? .dashboard
+        self.activeSidebarItem = storedLaunchItem ?? .dashboard
+        self.preventsDisplaySleepDuringWork = userDefaults.bool(forKey: DefaultsKey.preventsDisplaySleepDuringWork)
+        self.pausesOnScreenLock = userDefaults.bool(forKey: DefaultsKey.pausesOnScreenLock)
+        self.isMenuBarItemEnabled = userDefaults.object(forKey: DefaultsKey.menuBarItemEnabled) as? Bool ?? true
+        self.showsBreakOverlay = userDefaults.bool(forKey: DefaultsKey.showsBreakOverlay)
+        self.breakOverlayDisplayID = (userDefaults.object(forKey: DefaultsKey.breakOverlayDisplayID) as? NSNumber)?.uint32Value
+        self.menuBarLayout = MenuBarLayout(
+            rawValue: userDefaults.string(forKey: DefaultsKey.menuBarLayout) ?? ""
+        ) ?? .detailed
+        self.menuBarPrimaryTimer = MenuBarPrimaryTimer(
+            rawValue: userDefaults.string(forKey: DefaultsKey.menuBarPrimaryTimer) ?? ""
+        ) ?? .automatic
+        self.timerDisplayMode = TimerDisplayMode(
+            rawValue: userDefaults.string(forKey: DefaultsKey.timerDisplayMode) ?? ""
+        ) ?? .remaining
+        self.pomodoroResetScope = PomodoroResetScope(
+            rawValue: userDefaults.string(forKey: DefaultsKey.pomodoroResetScope) ?? ""
+        ) ?? .cycle
+        self.menuBarWidth = MenuBarWidth(
+            rawValue: userDefaults.string(forKey: DefaultsKey.menuBarWidth) ?? ""
+        ) ?? .fixed
+        self.menuBarModeIndicator = MenuBarModeIndicator(
+            rawValue: userDefaults.string(forKey: DefaultsKey.menuBarModeIndicator) ?? ""
+        ) ?? .emoji
         self.lastPomodoroState = pomodoro.state
         self.lastCountdownState = countdown.state
         self.hasRequestedNotificationAuthorization = userDefaults.bool(
@@ -126,6 +451,14 @@ final class AppState: ObservableObject {
             }
             .store(in: &cancellables)
 
+        // Only the registered custom player is shown in settings; polling updates stay local to the router.
+        nowPlayingRouter.$customPlayer
+            .dropFirst()
+            .sink { [weak self] _ in
+                self?.objectWillChange.send()
+            }
+            .store(in: &cancellables)
+
         pomodoro.$mode
             .removeDuplicates()
             .sink { [weak self] mode in
@@ -162,6 +495,13 @@ final class AppState: ObservableObject {
             }
             .store(in: &cancellables)
 
+        pomodoro.$programStepIndex
+            .removeDuplicates()
+            .sink { [weak self] index in
+                self?.handleProgramStepChange(index)
+            }
+            .store(in: &cancellables)
+
         countdown.$remainingSeconds
             .removeDuplicates()
             .sink { [weak self] seconds in
@@ -176,8 +516,48 @@ final class AppState: ObservableObject {
             }
             .store(in: &cancellables)
 
+        NotificationCenter.default.publisher(for: .NSSystemClockDidChange)
+            .receive(on: DispatchQueue.main)
+            .sink { [weak self] _ in
+                self?.handleSystemClockChange()
+            }
+            .store(in: &cancellables)
+
+        NSWorkspace.shared.notificationCenter.publisher(for: NSWorkspace.didWakeNotification)
+            .receive(on: DispatchQueue.main)
+            .sink { [weak self] _ in
+                self?.armSnoozeEnd()
+                self?.armMenuBarTitleOverrideExpiry()
+            }
+            .store(in: &cancellables)
+
+        // The lock screen has no public API; these distributed notifications are what loginwindow posts.
+        let distributedCenter = DistributedNotificationCenter.default()
+        distributedCenter.publisher(for: Notification.Name("com.apple.screenIsLocked"))
+            .receive(on: DispatchQueue.main)
+            .sink { [weak self] _ in
+                self?.handleScreenLock()
+            }
+            .store(in: &cancellables)
+        distributedCenter.publisher(for: Notification.Name("com.apple.screenIsUnlocked"))
+            .receive(on: DispatchQueue.main)
+            .sink { [weak self] _ in
+                self?.handleScreenUnlock()
+            }
+            .store(in: &cancellables)
+
+        pomodoro.skipCountsTowardCycle = skipCountsTowardCycle
+        pomodoro.autoStartDelayWorkSeconds = autoStartDelayWorkSeconds
+        pomodoro.autoStartDelayBreakSeconds = autoStartDelayBreakSeconds
+        pomodoro.minimumBreakSeconds = minimumBreakSeconds
+        pomodoro.shortBreakGrowthSeconds = shortBreakGrowthSeconds
+        pomodoro.shouldAutoStartWork = { [weak self] in
+            self?.allowsAutoStartingWork() ?? true
+        }
+
         updatePomodoroConfiguration()
         refreshDailyStatsForCurrentDay()
+        armScheduledQuit()
         requestNotificationAuthorizationIfNeeded()
     }
 
@@ -195,8 +575,13 @@ final class AppState: ObservableObject {
             countdown: countdown,
             durationConfig: storedConfig,
             userDefaults: userDefaults,
-            ambientNoiseEngine: ambientNoiseEngine
+            ambientNoiseEngine: ambientNoiseEngine,
+            sessionRecordStore: .shared,
+            analyticsStore: .shared
         )
+        if historyRetentionDays > 0 {
+            pruneHistory(olderThanDays: historyRetentionDays)
+        }
     }
 
     // Convenience initializer with explicit standard UserDefaults
@@ -250,8 +635,56 @@ final class AppState: ObservableObject {
         durationConfig = config
     }
 
+    /// Changes only the number of work sessions per long break, keeping the current cycle's progress.
+    /// With `k` work sessions completed since the last long break, the long break follows work session
+    /// `max(interval, k + 1)` of the cycle; a running session keeps its remaining time.
+    func setLongBreakInterval(_ interval: Int) {
+        let config = durationConfig
+        applyCustomDurationConfig(DurationConfig(
+            workDuration: config.workDuration,
+            shortBreakDuration: config.shortBreakDuration,
+            longBreakDuration: config.longBreakDuration,
+            longBreakInterval: interval,
+            countdownDuration: config.countdownDuration
+        ))
+    }
+
+    /// Repeated start requests, e.g. from a double click, leave a running session alone and don't
+    /// post `.timerDidStart` again. The same goes for the other start methods.
     func startPomodoro() {
+        guard pomodoro.state == .idle else { return }
         pomodoro.start()
+        postTimerDidStartIfRunning(pomodoro.state)
+    }
+
+    /// Starts a work session shortened so its break begins at the next multiple of `minutes` past
+    /// the hour, e.g. :00 and :30 for 30. Subsequent sessions use the normal durations.
+    func startPomodoroAlignedToClock(minutes: Int, now: Date = Date()) {
+        guard pomodoro.state == .idle else { return }
+        pomodoro.start(firstSessionSeconds: Self.secondsUntilClockBoundary(everyMinutes: minutes, from: now))
+        postTimerDidStartIfRunning(pomodoro.state)
+    }
+
+    private func postTimerDidStartIfRunning(_ state: TimerState) {
+        guard state == .running else { return }
+        NotificationCenter.default.post(name: .timerDidStart, object: self)
+    }
+
+    private static func secondsUntilClockBoundary(everyMinutes minutes: Int, from date: Date) -> Int {
+        let hourSeconds = 60 * 60
+        let step = min(max(1, minutes), 60) * 60
+        let components = Calendar.current.dateComponents([.minute, .second], from: date)
+        let secondsIntoHour = (components.minute ?? 0) * 60 + (components.second ?? 0)
+        // Boundaries restart at the top of each hour, so steps that do not divide 60 still hit :00.
+        let nextBoundary = min((secondsIntoHour / step + 1) * step, hourSeconds)
+        guard nextBoundary - secondsIntoHour < 60 else {
+            return nextBoundary - secondsIntoHour
+        }
+        // Less than a minute away is too short to be a session; aim for the boundary after it.
+        let followingBoundary = nextBoundary == hourSeconds
+            ? hourSeconds + step
+            : min(nextBoundary + step, hourSeconds)
+        return followingBoundary - secondsIntoHour
     }
 
     func previewPomodoroSession(durationConfig: DurationConfig) {
@@ -298,6 +731,15 @@ final class AppState: ObservableObject {
         }
     }
 
+    /// Unlike `togglePomodoroPause()`, asking twice doesn't undo the first request.
+    func pausePomodoro() {
+        pomodoro.pause()
+    }
+
+    func resumePomodoro() {
+        pomodoro.resume()
+    }
+
     func togglePomodoroPause() {
         switch pomodoro.state {
         case .running, .breakRunning:
@@ -309,7 +751,14 @@ final class AppState: ObservableObject {
         }
     }
 
+    /// See `PomodoroResetScope`. A full reset, including any reset while idle, also clears the
+    /// execution plan; a session-only reset keeps it going.
     func resetPomodoro() {
+        pomodoroWasRunningBeforeBulkPause = false
+        if pomodoroResetScope == .session, pomodoro.state != .idle {
+            pomodoro.restartSession()
+            return
+        }
         pomodoro.reset()
         clearExecutionPlan()
         updatePomodoroConfiguration()
@@ -319,12 +768,230 @@ final class AppState: ObservableObject {
         pomodoro.startBreak()
     }
 
+    /// Ignored until the break has run for `minimumBreakSeconds`.
     func skipBreak() {
+        guard pomodoro.canSkipBreak else { return }
         pomodoro.skipBreak()
     }
 
+    func extendBreak(byMinutes minutes: Int) {
+        guard pomodoro.state == .breakRunning || pomodoro.state == .breakPaused else { return }
+        let seconds = minutes * 60
+        pomodoro.extendBreak(bySeconds: seconds)
+        // The logged planned length includes the extension.
+        currentBreakDurationSeconds = currentBreakDurationSeconds.map { $0 + seconds }
+    }
+
+    /// Shows `text` in the menu bar instead of the timer for `durationSeconds`, after which the timer
+    /// title returns. Empty text or a non-positive duration clears any current override.
+    func setMenuBarTitleOverride(_ text: String, durationSeconds: Int, now: Date = Date()) {
+        let trimmedText = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmedText.isEmpty, durationSeconds > 0 else {
+            clearMenuBarTitleOverride()
+            return
+        }
+        menuBarTitleOverride = MenuBarTitleOverride(
+            text: trimmedText,
+            expiresAt: now.addingTimeInterval(TimeInterval(durationSeconds))
+        )
+        armMenuBarTitleOverrideExpiry(now: now)
+    }
+
+    /// Clears the override once it expires; the status item shows it for as long as it is set.
+    /// Like `armSnoozeEnd(now:)`, called again after wake and clock changes.
+    func armMenuBarTitleOverrideExpiry(now: Date = Date()) {
+        menuBarTitleOverrideTimer?.invalidate()
+        menuBarTitleOverrideTimer = nil
+        guard let menuBarTitleOverride else { return }
+        guard menuBarTitleOverride.expiresAt > now else {
+            clearMenuBarTitleOverride()
+            return
+        }
+        menuBarTitleOverrideTimer = Timer.scheduledTimer(
+            withTimeInterval: menuBarTitleOverride.expiresAt.timeIntervalSince(now),
+            repeats: false
+        ) { [weak self] _ in
+            self?.clearMenuBarTitleOverride()
+        }
+    }
+
+    func clearMenuBarTitleOverride() {
+        menuBarTitleOverrideTimer?.invalidate()
+        menuBarTitleOverrideTimer = nil
+        menuBarTitleOverride = nil
+    }
+
+    /// Nudges the timer the menu bar title follows by `delta` seconds, for dial-style controls. The
+    /// countdown is clamped to its duration; a Pomodoro session grows instead. Reaching zero
+    /// completes the timer, and counts as finishing it even from a pause.
+    func adjustActiveTimerRemaining(bySeconds delta: Int) {
+        let countdownActive = countdown.state == .running || countdown.state == .paused
+        let pomodoroActive = pomodoro.state != .idle
+        if adjustsCountdown {
+            if countdownActive, countdown.remainingSeconds + delta <= 0 {
+                countdownDidReachZero = true
+            }
+            countdown.setRemaining(countdown.remainingSeconds + delta)
+        } else if pomodoroActive {
+            let previousLength = pomodoro.sessionDurationSeconds
+            if pomodoro.remainingSeconds + delta <= 0 {
+                pomodoroDidReachZero = true
+            }
+            pomodoro.adjustRemaining(bySeconds: delta)
+            // The logged planned length follows a session that grew; a completed one was already logged.
+            let added = pomodoro.sessionDurationSeconds - previousLength
+            if added > 0 {
+                switch pomodoro.state {
+                case .running, .paused:
+                    currentFocusDurationSeconds = currentFocusDurationSeconds.map { $0 + added }
+                case .breakRunning, .breakPaused:
+                    currentBreakDurationSeconds = currentBreakDurationSeconds.map { $0 + added }
+                case .idle:
+                    break
+                }
+            }
+        }
+    }
+
+    /// Whether `adjustActiveTimerRemaining(bySeconds:)` changes the countdown rather than the Pomodoro.
+    var adjustsCountdown: Bool {
+        let countdownActive = countdown.state == .running || countdown.state == .paused
+        switch menuBarPrimaryTimer {
+        case .automatic:
+            return countdownActive
+        case .countdown:
+            // Follows the title, which stays on the countdown even while it is idle.
+            return true
+        case .pomodoro:
+            return countdownActive && pomodoro.state == .idle
+        }
+    }
+
+    /// `DurationConfig.transitionRulesDescription` filled in with the current auto-start settings.
+    func transitionRulesDescription() -> String {
+        durationConfig.transitionRulesDescription(
+            autoStartDelayWorkSeconds: autoStartDelayWorkSeconds,
+            autoStartDelayBreakSeconds: autoStartDelayBreakSeconds,
+            workHours: workHours,
+            maxConsecutiveAutoStarts: maxConsecutiveAutoStarts,
+            shortBreakGrowthSeconds: shortBreakGrowthSeconds
+        )
+    }
+
+    /// `true` once today's focus time reaches `dailyFocusCapSeconds`, unless overridden for today.
+    /// The cap resets at local midnight along with the daily stats.
+    var isDailyFocusCapReached: Bool {
+        guard dailyFocusCapSeconds > 0 else { return false }
+        if let dailyFocusCapOverrideDay, Calendar.current.isDateInToday(dailyFocusCapOverrideDay) {
+            return false
+        }
+        return todayStats.totalFocusSeconds >= dailyFocusCapSeconds
+    }
+
+    /// `true` once today's completed work sessions reach `dailySessionGoal`. Resets at local midnight.
+    var isDailySessionGoalReached: Bool {
+        dailySessionGoal > 0 && todayStats.completedFocusSessions >= dailySessionGoal
+    }
+
+    /// Lifts the daily focus cap until local midnight.
+    func overrideDailyFocusCap() {
+        let today = Calendar.current.startOfDay(for: Date())
+        dailyFocusCapOverrideDay = today
+        userDefaults.set(today, forKey: DefaultsKey.dailyFocusCapOverrideDay)
+    }
+
+    private var todayStats: DailyStats {
+        var stats = dailyStats
+        stats.ensureCurrentDay()
+        return stats
+    }
+
+    private func allowsAutoStartingWork() -> Bool {
+        if snoozedUntil != nil {
+            return false
+        }
+        if isDailyFocusCapReached {
+            return false
+        }
+        if stopsAutoStartAtDailyGoal, isDailySessionGoalReached {
+            return false
+        }
+        if let workHours, !workHours.contains(Date()) {
+            return false
+        }
+        if isAutoStartLimitReached {
+            return false
+        }
+        return true
+    }
+
+    private var isAutoStartLimitReached: Bool {
+        maxConsecutiveAutoStarts > 0 && pomodoro.consecutiveAutoStarts >= maxConsecutiveAutoStarts
+    }
+
+    /// Lets the user know the timer stopped at `maxConsecutiveAutoStarts` instead of carrying on.
+    func announceAutoStartLimitReached() {
+        let l10n = LocalizationManager.shared
+        sendNotification(
+            title: decoratedTitle(l10n.text("notification.auto_start_limit_title"), emoji: "⏸"),
+            body: l10n.format("notification.auto_start_limit_body", maxConsecutiveAutoStarts)
+        )
+    }
+
+    /// `true` while timers paused by `pauseAllTimers()` are waiting for `resumeAllTimers()`.
+    var hasBulkPausedTimers: Bool {
+        pomodoroWasRunningBeforeBulkPause || countdownWasRunningBeforeBulkPause
+    }
+
+    /// Pauses every running timer and remembers which ones were running.
+    func pauseAllTimers() {
+        let paused = pauseRunningTimers()
+        if paused.pomodoro {
+            pomodoroWasRunningBeforeBulkPause = true
+        }
+        if paused.countdown {
+            countdownWasRunningBeforeBulkPause = true
+        }
+    }
+
+    /// Pauses whichever timers are running and reports which ones it paused.
+    private func pauseRunningTimers() -> (pomodoro: Bool, countdown: Bool) {
+        var paused = (pomodoro: false, countdown: false)
+        if pomodoro.state == .running || pomodoro.state == .breakRunning {
+            pomodoro.pause()
+            paused.pomodoro = true
+        }
+        if countdown.state == .running {
+            countdown.pause()
+            paused.countdown = true
+        }
+        return paused
+    }
+
+    /// Resumes only the timers `pauseAllTimers()` paused, so a stopped countdown is never started.
+    func resumeAllTimers() {
+        if pomodoroWasRunningBeforeBulkPause, pomodoro.state == .paused || pomodoro.state == .breakPaused {
+            pomodoro.resume()
+        }
+        if countdownWasRunningBeforeBulkPause, countdown.state == .paused {
+            countdown.resume()
+        }
+        pomodoroWasRunningBeforeBulkPause = false
+        countdownWasRunningBeforeBulkPause = false
+    }
+
     func startCountdown() {
+        guard countdown.state == .idle else { return }
         countdown.start()
+        postTimerDidStartIfRunning(countdown.state)
+    }
+
+    func pauseCountdown() {
+        countdown.pause()
+    }
+
+    func resumeCountdown() {
+        countdown.resume()
     }
 
     func toggleCountdownPause() {
@@ -339,16 +1006,713 @@ final class AppState: ObservableObject {
     }
 
     func resetCountdown() {
+        countdownWasRunningBeforeBulkPause = false
         countdown.reset()
     }
 
+    func setCountdownRemaining(seconds: Int) {
+        if seconds <= 0, countdown.state == .running || countdown.state == .paused {
+            countdownDidReachZero = true
+        }
+        countdown.setRemaining(seconds)
+    }
+
     private enum DefaultsKey {
         static let notificationPreference = "notification.preference"
         static let reminderPreference = "notification.reminderPreference"
         static let notificationDeliveryStyle = "notification.deliveryStyle"
+        static let notificationGroup = "notification.group"
+        static let stateMirrorPath = "integration.stateMirrorPath"
         static let dailyStats = "dailyStats.current"
+        static let focusStreak = "dailyStats.focusStreak"
         static let presetSelection = "durationConfig.presetSelection"
         static let notificationAuthorizationRequested = "notification.authorizationRequested"
+        static let menuBarIdleTitle = "menuBar.idleTitle"
+        static let menuBarGlyphs = "menuBar.glyphs"
+        static let menuBarPrimaryTimer = "menuBar.primaryTimer"
+        static let menuBarItemEnabled = "menuBar.itemEnabled"
+        static let showsBreakOverlay = "breakOverlay.enabled"
+        static let breakOverlayDisplayID = "breakOverlay.displayID"
+        static let hidesWindowOnStart = "window.hidesOnStart"
+        static let quitsWhenLastWindowClosed = "window.quitsWhenLastWindowClosed"
+        static let scheduledQuitDate = "app.scheduledQuitDate"
+        static let launchSidebarItem = "window.launchTab"
+        static let preventsDisplaySleepDuringWork = "timer.preventsDisplaySleepDuringWork"
+        static let pausesOnScreenLock = "timer.pausesOnScreenLock"
+        static let menuBarLayout = "menuBar.layout"
+        static let menuBarModeIndicator = "menuBar.modeIndicator"
+        static let menuBarWidth = "menuBar.width"
+        static let timerDisplayMode = "timer.displayMode"
+        static let pomodoroResetScope = "timer.resetScope"
+        static let dailyFocusCapSeconds = "focusLimits.dailyCapSeconds"
+        static let dailyFocusCapOverrideDay = "focusLimits.dailyCapOverrideDay"
+        static let skipCountsTowardCycle = "durationConfig.skipCountsTowardCycle"
+        static let autoStartDelayWorkSeconds = "durationConfig.autoStartDelayWorkSeconds"
+        static let autoStartDelayBreakSeconds = "durationConfig.autoStartDelayBreakSeconds"
+        static let minimumBreakSeconds = "durationConfig.minimumBreakSeconds"
+        static let shortBreakGrowthSeconds = "durationConfig.shortBreakGrowthSeconds"
+        static let dailySessionGoal = "focusLimits.dailySessionGoal"
+        static let weeklyFocusGoalSeconds = "focusLimits.weeklyFocusGoalSeconds"
+        static let historyRetentionDays = "history.retentionDays"
+        static let maxConsecutiveAutoStarts = "focusLimits.maxConsecutiveAutoStarts"
+        static let workHours = "focusLimits.workHours"
+        static let weekStartDay = "focusLimits.weekStartDay"
+        static let countsBreaksAsSessions = "dailyStats.countsBreaksAsSessions"
+        static let dailyGoalStopsAutoStart = "focusLimits.dailyGoalStopsAutoStart"
+        static let completionSoundPath = "notification.completionSoundPath"
+        static let completionSoundBookmark = "notification.completionSoundBookmark"
+        static let notificationBodyTemplate = "notification.bodyTemplate"
+        static let intervalProgram = "timer.intervalProgram"
+    }
+
+    /// Validates and stores a custom completion chime. An empty path restores the default sound.
+    func setCompletionSoundFile(path: String) throws {
+        let trimmedPath = path.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmedPath.isEmpty else {
+            completionSoundPath = nil
+            userDefaults.removeObject(forKey: DefaultsKey.completionSoundPath)
+            userDefaults.removeObject(forKey: DefaultsKey.completionSoundBookmark)
+            return
+        }
+        let url = URL(fileURLWithPath: trimmedPath)
+        try CompletionSoundPlayer.validate(url)
+        completionSoundPath = trimmedPath
+        userDefaults.set(trimmedPath, forKey: DefaultsKey.completionSoundPath)
+        userDefaults.set(CompletionSoundPlayer.bookmark(for: url), forKey: DefaultsKey.completionSoundBookmark)
+    }
+
+    struct TimerFinishTime: Equatable {
+        enum Source: Equatable {
+            case pomodoro(PomodoroTimerEngine.CurrentMode)
+            case countdown
+        }
+
+        let source: Source
+        let date: Date
+    }
+
+    /// Seconds the Pomodoro display shows under `timerDisplayMode`.
+    var displayedPomodoroSeconds: Int {
+        timerDisplayMode == .elapsed ? pomodoro.elapsedSeconds : pomodoro.remainingSeconds
+    }
+
+    /// Seconds the countdown display shows under `timerDisplayMode`.
+    var displayedCountdownSeconds: Int {
+        timerDisplayMode == .elapsed ? countdown.elapsedSeconds : countdown.remainingSeconds
+    }
+
+    /// When the running Pomodoro or countdown will reach zero, or `nil` if neither is running.
+    /// If both are running, the one that finishes first.
+    func timerFinishTime(now: Date = Date()) -> TimerFinishTime? {
+        var candidates: [TimerFinishTime] = []
+        if pomodoro.state == .running || pomodoro.state == .breakRunning {
+            candidates.append(TimerFinishTime(
+                source: .pomodoro(pomodoro.currentMode),
+                date: now.addingTimeInterval(TimeInterval(pomodoro.remainingSeconds))
+            ))
+        }
+        if countdown.state == .running {
+            candidates.append(TimerFinishTime(
+                source: .countdown,
+                date: now.addingTimeInterval(TimeInterval(countdown.remainingSeconds))
+            ))
+        }
+        return candidates.min { $0.date < $1.date }
+    }
+
+    func weeklyFocusProgress(now: Date = Date()) -> WeeklyFocusProgress {
+        WeeklyFocusProgress(
+            goalSeconds: weeklyFocusGoalSeconds,
+            completedSeconds: analyticsStore.focusSecondsInWeek(containing: now, firstWeekday: weekStartDay)
+        )
+    }
+
+    struct LaunchSummary: Equatable {
+        let uptimeSeconds: Int
+        let completedFocusSessionsThisLaunch: Int
+        let completedFocusSessionsAllTime: Int
+    }
+
+    @MainActor
+    func launchSummary(now: Date = Date()) -> LaunchSummary {
+        LaunchSummary(
+            uptimeSeconds: max(0, Int(now.timeIntervalSince(launchedAt))),
+            completedFocusSessionsThisLaunch: completedFocusSessionsThisLaunch,
+            completedFocusSessionsAllTime: sessionRecordStore.completedFocusSessionCount()
+        )
+    }
+
+    /// Validates and stores a custom notification body. An empty template restores the built-in text.
+    func setNotificationBodyTemplate(_ text: String) throws {
+        let trimmedText = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmedText.isEmpty else {
+            notificationBodyTemplate = nil
+            userDefaults.removeObject(forKey: DefaultsKey.notificationBodyTemplate)
+            return
+        }
+        let template = try NotificationTemplate(trimmedText)
+        notificationBodyTemplate = template
+        userDefaults.set(template.text, forKey: DefaultsKey.notificationBodyTemplate)
+    }
+
+    /// Validates and loads the routine `startIntervalProgram()` runs. Empty text unloads it.
+    /// Throws without changing anything while a program is running.
+    func setIntervalProgram(_ text: String) throws {
+        guard pomodoro.programStepIndex == nil else {
+            throw IntervalProgramError.programRunning
+        }
+        let trimmedText = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmedText.isEmpty else {
+            pomodoro.loadProgram(nil)
+            userDefaults.removeObject(forKey: DefaultsKey.intervalProgram)
+            return
+        }
+        let program = try IntervalProgram(trimmedText)
+        pomodoro.loadProgram(program)
+        userDefaults.set(program.text, forKey: DefaultsKey.intervalProgram)
+    }
+
+    func startIntervalProgram() {
+        guard pomodoro.state == .idle else { return }
+        pomodoro.startProgram()
+        postTimerDidStartIfRunning(pomodoro.state)
+    }
+
+    func stopIntervalProgram() {
+        pomodoro.stopProgram()
+    }
+
+    /// Removes session history from before the last `days` days, counting today. Returns how many
+    /// sessions were removed.
+    @MainActor
+    @discardableResult
+    func pruneHistory(olderThanDays days: Int) -> Int {
+        sessionRecordStore.removeRecords(olderThanDays: days)
+    }
+
+    /// Quits the app at `date`, with a warning a minute before. Returns `false` without scheduling
+    /// anything if `date` has already passed.
+    @discardableResult
+    func scheduleQuit(at date: Date, now: Date = Date()) -> Bool {
+        guard date > now else { return false }
+        scheduledQuitDate = date
+        userDefaults.set(date, forKey: DefaultsKey.scheduledQuitDate)
+        armScheduledQuit(now: now)
+        return true
+    }
+
+    func clearScheduledQuit() {
+        scheduledQuitDate = nil
+        userDefaults.removeObject(forKey: DefaultsKey.scheduledQuitDate)
+        armScheduledQuit()
+    }
+
+    /// Parks the app until `date`: pauses running timers, holds notifications and auto-start, and
+    /// optionally hides the main window. A timer started during the snooze is paused again and
+    /// resumes with the others when it ends. Snoozing again only moves the end time.
+    /// Returns `false` without changing anything if `date` has already passed.
+    @discardableResult
+    func snooze(until date: Date, hidesWindow: Bool = false, now: Date = Date()) -> Bool {
+        guard date > now else { return false }
+        holdRunningTimersForSnooze()
+        snoozedUntil = date
+        armSnoozeEnd(now: now)
+        NotificationCenter.default.post(
+            name: .snoozeDidStart,
+            object: self,
+            userInfo: [Self.snoozeHidesWindowKey: hidesWindow]
+        )
+        return true
+    }
+
+    /// Ends a snooze early or on time, resuming whatever it paused.
+    func endSnooze() {
+        guard snoozedUntil != nil else { return }
+        snoozeTimer?.invalidate()
+        snoozeTimer = nil
+        snoozedUntil = nil
+        if snoozePausedTimers.pomodoro, pomodoro.state == .paused || pomodoro.state == .breakPaused {
+            pomodoro.resume()
+        }
+        if snoozePausedTimers.countdown, countdown.state == .paused {
+            countdown.resume()
+        }
+        snoozePausedTimers = (false, false)
+        NotificationCenter.default.post(name: .snoozeDidEnd, object: self)
+    }
+
+    /// The end timer counts elapsed time, which stops during sleep and ignores clock changes, so
+    /// wake and clock changes call this again to measure from `snoozedUntil` afresh.
+    func armSnoozeEnd(now: Date = Date()) {
+        snoozeTimer?.invalidate()
+        snoozeTimer = nil
+        guard let snoozedUntil else { return }
+        guard snoozedUntil > now else {
+            endSnooze()
+            return
+        }
+        snoozeTimer = Timer.scheduledTimer(withTimeInterval: snoozedUntil.timeIntervalSince(now), repeats: false) { [weak self] _ in
+            self?.endSnooze()
+        }
+    }
+
+    private func holdRunningTimersForSnooze() {
+        let paused = pauseRunningTimers()
+        snoozePausedTimers.pomodoro = snoozePausedTimers.pomodoro || paused.pomodoro
+        snoozePausedTimers.countdown = snoozePausedTimers.countdown || paused.countdown
+    }
+
+    /// Called from the state sinks, which run before the engine finishes changing state, so the
+    /// pause waits a turn of the main queue.
+    private func holdTimerStartedDuringSnooze(_ state: TimerState) {
+        guard snoozedUntil != nil, state == .running || state == .breakRunning else { return }
+        DispatchQueue.main.async { [weak self] in
+            guard let self, self.snoozedUntil != nil else { return }
+            self.holdRunningTimersForSnooze()
+        }
+    }
+
+    private func armScheduledQuit(now: Date = Date()) {
+        scheduledQuitTimers.forEach { $0.invalidate() }
+        scheduledQuitTimers.removeAll()
+        guard let scheduledQuitDate else { return }
+
+        let warningDate = scheduledQuitDate.addingTimeInterval(-Self.scheduledQuitWarningSeconds)
+        if warningDate > now {
+            scheduledQuitTimers.append(Timer.scheduledTimer(
+                withTimeInterval: warningDate.timeIntervalSince(now),
+                repeats: false
+            ) { [weak self] _ in
+                self?.sendScheduledQuitWarning()
+            })
+        }
+        scheduledQuitTimers.append(Timer.scheduledTimer(
+            withTimeInterval: max(0, scheduledQuitDate.timeIntervalSince(now)),
+            repeats: false
+        ) { [weak self] _ in
+            Task { @MainActor in
+                self?.quitForSchedule()
+            }
+        })
+    }
+
+    /// Delivered even with notifications off or snoozed: it is the only chance to cancel the quit.
+    private func sendScheduledQuitWarning() {
+        guard let scheduledQuitDate else { return }
+        let l10n = LocalizationManager.shared
+        deliverNotification(
+            title: l10n.text("notification.quit_warning.title"),
+            body: l10n.format("notification.quit_warning.body", l10n.timeString(from: scheduledQuitDate)),
+            isCompletion: false
+        )
+    }
+
+    @MainActor
+    private func quitForSchedule() {
+        guard let scheduledQuitDate else { return }
+        clearScheduledQuit()
+        // Timers don't fire during sleep; waking on a later day shouldn't close the app.
+        guard Calendar.current.isDateInToday(scheduledQuitDate) else { return }
+        flushPersistedState()
+        NSApplication.shared.terminate(nil)
+    }
+
+    /// Writes settings, today's stats and session history to disk right away, returning `false` if
+    /// any write failed. Running timers are not persisted, so there is no timer state to flush.
+    @MainActor
+    @discardableResult
+    func flushPersistedState() -> Bool {
+        durationConfig.save(to: userDefaults)
+        saveDailyStats(dailyStats)
+        let recordsSaved = sessionRecordStore.flush()
+        return userDefaults.synchronize() && recordsSaved
+    }
+
+    /// Base64 snapshot of settings, today's stats and session history. See `StateSnapshot`.
+    @MainActor
+    func exportStateBlob() throws -> String {
+        let snapshot = StateSnapshot(
+            preferences: try StateSnapshot.Preferences(settingsDictionary()),
+            dailyStats: dailyStats,
+            sessionRecords: sessionRecordStore.records
+        )
+        return try snapshot.encodedBlob()
+    }
+
+    /// Restores a blob from `exportStateBlob()`. The blob is fully decoded and checked before
+    /// anything is applied, so a rejected import leaves the current state untouched.
+    @MainActor
+    func importStateBlob(_ blob: String) throws {
+        let snapshot = try StateSnapshot.decode(blob: blob)
+        let settings = try snapshot.preferences.settings()
+        guard pomodoro.state == .idle, countdown.state == .idle else {
+            throw StateSnapshotError.timerActive
+        }
+
+        applySettings(settings)
+        dailyStats = snapshot.dailyStats
+        saveDailyStats(snapshot.dailyStats)
+        refreshDailyStatsForCurrentDay()
+        sessionRecordStore.replaceRecords(snapshot.sessionRecords)
+    }
+
+    enum FactoryResetItem: CaseIterable {
+        case settings
+        case todayStats
+        case sessionHistory
+        case customMediaPlayer
+        case focusSoundUsage
+    }
+
+    /// Stops both timers and restores every setting to its default, clearing today's stats, the
+    /// session history and any custom media player. Returns what was cleared. Callers must confirm
+    /// with the user first; nothing here can be undone.
+    @MainActor
+    @discardableResult
+    func resetToFactoryDefaults() -> [FactoryResetItem] {
+        resetPomodoro()
+        resetCountdown()
+
+        durationConfig = .standard
+        presetSelection = PresetSelection.selection(for: .standard)
+        notificationPreference = .off
+        notificationDeliveryStyle = .system
+        reminderPreference = .off
+        notificationGroup = nil
+        stateMirrorPath = nil
+        try? setCompletionSoundFile(path: "")
+        try? setNotificationBodyTemplate("")
+        try? setIntervalProgram("")
+        menuBarIdleTitle = nil
+        menuBarGlyphs = .standard
+        isMenuBarItemEnabled = true
+        showsBreakOverlay = false
+        breakOverlayDisplayID = nil
+        hidesWindowOnStart = false
+        quitsWhenLastWindowClosed = false
+        launchSidebarItem = .dashboard
+        preventsDisplaySleepDuringWork = false
+        pausesOnScreenLock = false
+        menuBarLayout = .detailed
+        menuBarPrimaryTimer = .automatic
+        menuBarModeIndicator = .emoji
+        menuBarWidth = .fixed
+        timerDisplayMode = .remaining
+        pomodoroResetScope = .cycle
+        dailyFocusCapSeconds = 0
+        dailyFocusCapOverrideDay = nil
+        userDefaults.removeObject(forKey: DefaultsKey.dailyFocusCapOverrideDay)
+        dailySessionGoal = 0
+        stopsAutoStartAtDailyGoal = false
+        maxConsecutiveAutoStarts = 0
+        workHours = nil
+        weeklyFocusGoalSeconds = 0
+        historyRetentionDays = 0
+        weekStartDay = 2
+        skipCountsTowardCycle = true
+        autoStartDelayWorkSeconds = 0
+        autoStartDelayBreakSeconds = 0
+        minimumBreakSeconds = 0
+        shortBreakGrowthSeconds = 0
+        countsBreaksAsSessions = true
+
+        dailyStats = DailyStats()
+        saveDailyStats(dailyStats)
+        resetFocusStreak()
+        sessionRecordStore.replaceRecords([])
+        nowPlayingRouter.removeCustomPlayer()
+        musicController?.resetSettingsToDefaults()
+        return FactoryResetItem.allCases
+    }
+
+    /// Writes every user setting to `url` as a `SettingsDocument`. Stats and history are not included.
+    func exportSettings(to url: URL) throws {
+        try SettingsDocument.write(settingsDictionary(), to: url)
+    }
+
+    /// Every user setting as plain JSON values, keyed as in a `SettingsDocument`.
+    func settingsDictionary() -> [String: Any] {
+        var settings: [String: Any] = [
+            "workDuration": durationConfig.workDuration,
+            "shortBreakDuration": durationConfig.shortBreakDuration,
+            "longBreakDuration": durationConfig.longBreakDuration,
+            "longBreakInterval": durationConfig.longBreakInterval,
+            "countdownDuration": durationConfig.countdownDuration,
+            "notificationPreference": notificationPreference.rawValue,
+            "notificationDeliveryStyle": notificationDeliveryStyle.rawValue,
+            "reminderPreference": reminderPreference.rawValue,
+            "notificationGroup": notificationGroup ?? NSNull(),
+            "stateMirrorPath": stateMirrorPath ?? NSNull(),
+            "notificationBodyTemplate": notificationBodyTemplate?.text ?? NSNull(),
+            "intervalProgram": pomodoro.program?.text ?? NSNull(),
+            "menuBarPrimaryTimer": menuBarPrimaryTimer.rawValue,
+            "menuBarLayout": menuBarLayout.rawValue,
+            "menuBarModeIndicator": menuBarModeIndicator.rawValue,
+            "menuBarWidth": menuBarWidth.rawValue,
+            "timerDisplayMode": timerDisplayMode.rawValue,
+            "pomodoroResetScope": pomodoroResetScope.rawValue,
+            "menuBarIdleTitle": menuBarIdleTitle ?? NSNull(),
+            "menuBarGlyphs": menuBarGlyphs.dictionary,
+            "isMenuBarItemEnabled": isMenuBarItemEnabled,
+            "showsBreakOverlay": showsBreakOverlay,
+            "breakOverlayDisplayID": breakOverlayDisplayID.map { Int($0) } ?? NSNull(),
+            "hidesWindowOnStart": hidesWindowOnStart,
+            "quitsWhenLastWindowClosed": quitsWhenLastWindowClosed,
+            "launchSidebarItem": launchSidebarItem.rawValue,
+            "preventsDisplaySleepDuringWork": preventsDisplaySleepDuringWork,
+            "pausesOnScreenLock": pausesOnScreenLock,
+            "dailyFocusCapSeconds": dailyFocusCapSeconds,
+            "dailySessionGoal": dailySessionGoal,
+            "stopsAutoStartAtDailyGoal": stopsAutoStartAtDailyGoal,
+            "weeklyFocusGoalSeconds": weeklyFocusGoalSeconds,
+            "historyRetentionDays": historyRetentionDays,
+            "maxConsecutiveAutoStarts": maxConsecutiveAutoStarts,
+            "workHours": workHours?.dictionary ?? NSNull(),
+            "weekStartDay": weekStartDay,
+            "skipCountsTowardCycle": skipCountsTowardCycle,
+            "autoStartDelayWorkSeconds": autoStartDelayWorkSeconds,
+            "autoStartDelayBreakSeconds": autoStartDelayBreakSeconds,
+            "minimumBreakSeconds": minimumBreakSeconds,
+            "shortBreakGrowthSeconds": shortBreakGrowthSeconds,
+            "countsBreaksAsSessions": countsBreaksAsSessions
+        ]
+        if let musicController {
+            settings["focusSoundCrossfadeSeconds"] = musicController.focusSoundCrossfadeSeconds
+            settings["focusSoundRotation"] = musicController.focusSoundRotation?.dictionary ?? NSNull()
+            settings["countdownMutesFocusSound"] = musicController.countdownMutesFocusSound
+        }
+        return settings
+    }
+
+    /// Applies a file from `exportSettings(to:)` and returns the keys it skipped, sorted: settings
+    /// this version doesn't know and values of the wrong type or out of range. Everything else applies.
+    @discardableResult
+    func importSettings(from url: URL) throws -> [String] {
+        applySettings(try SettingsDocument.read(from: url))
+    }
+
+    /// Applies settings in the `settingsDictionary()` layout, returning the keys it skipped, sorted.
+    @discardableResult
+    func applySettings(_ settings: [String: Any]) -> [String] {
+        var work = durationConfig.workDuration
+        var shortBreak = durationConfig.shortBreakDuration
+        var longBreak = durationConfig.longBreakDuration
+        var interval = durationConfig.longBreakInterval
+        var countdownDuration = durationConfig.countdownDuration
+
+        func positive(_ value: Any, _ apply: (Int) -> Void) -> Bool {
+            guard let number = value as? Int, number > 0 else { return false }
+            apply(number)
+            return true
+        }
+        func nonNegative(_ value: Any, _ apply: (Int) -> Void) -> Bool {
+            guard let number = value as? Int, number >= 0 else { return false }
+            apply(number)
+            return true
+        }
+        func flag(_ value: Any, _ apply: (Bool) -> Void) -> Bool {
+            guard let flag = value as? Bool else { return false }
+            apply(flag)
+            return true
+        }
+        func choice<Choice: RawRepresentable>(_ value: Any, _ apply: (Choice) -> Void) -> Bool where Choice.RawValue == String {
+            guard let rawValue = value as? String, let choice = Choice(rawValue: rawValue) else { return false }
+            apply(choice)
+            return true
+        }
+
+        var skippedKeys: [String] = []
+        for (key, value) in settings {
+            let applied: Bool
+            switch key {
+            case "workDuration":
+                applied = positive(value) { work = $0 }
+            case "shortBreakDuration":
+                applied = positive(value) { shortBreak = $0 }
+            case "longBreakDuration":
+                applied = positive(value) { longBreak = $0 }
+            case "longBreakInterval":
+                applied = positive(value) { interval = $0 }
+            case "countdownDuration":
+                applied = positive(value) { countdownDuration = $0 }
+            case "notificationPreference":
+                applied = choice(value) { notificationPreference = $0 }
+            case "notificationDeliveryStyle":
+                applied = choice(value) { notificationDeliveryStyle = $0 }
+            case "reminderPreference":
+                applied = choice(value) { reminderPreference = $0 }
+            case "stateMirrorPath":
+                if value is NSNull {
+                    applied = true
+                    stateMirrorPath = nil
+                } else if let path = value as? String, !path.isEmpty {
+                    applied = true
+                    stateMirrorPath = path
+                } else {
+                    applied = false
+                }
+            case "notificationGroup":
+                if value is NSNull {
+                    applied = true
+                    notificationGroup = nil
+                } else if let group = value as? String, !group.isEmpty {
+                    applied = true
+                    notificationGroup = group
+                } else {
+                    applied = false
+                }
+            case "notificationBodyTemplate":
+                if let text = value as? String ?? (value is NSNull ? "" : nil) {
+                    applied = (try? setNotificationBodyTemplate(text)) != nil
+                } else {
+                    applied = false
+                }
+            case "intervalProgram":
+                if let text = value as? String ?? (value is NSNull ? "" : nil) {
+                    applied = (try? setIntervalProgram(text)) != nil
+                } else {
+                    applied = false
+                }
+            case "menuBarPrimaryTimer":
+                applied = choice(value) { menuBarPrimaryTimer = $0 }
+            case "menuBarLayout":
+                applied = choice(value) { menuBarLayout = $0 }
+            case "menuBarModeIndicator":
+                applied = choice(value) { menuBarModeIndicator = $0 }
+            case "timerDisplayMode":
+                applied = choice(value) { timerDisplayMode = $0 }
+            case "pomodoroResetScope":
+                applied = choice(value) { pomodoroResetScope = $0 }
+            case "menuBarWidth":
+                applied = choice(value) { menuBarWidth = $0 }
+            case "menuBarIdleTitle":
+                applied = value is String || value is NSNull
+                if applied {
+                    menuBarIdleTitle = value as? String
+                }
+            case "menuBarGlyphs":
+                if let dictionary = value as? [String: Any], let glyphs = MenuBarGlyphs(dictionary: dictionary) {
+                    menuBarGlyphs = glyphs
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "isMenuBarItemEnabled":
+                applied = flag(value) { isMenuBarItemEnabled = $0 }
+            case "showsBreakOverlay":
+                applied = flag(value) { showsBreakOverlay = $0 }
+            case "breakOverlayDisplayID":
+                if value is NSNull {
+                    breakOverlayDisplayID = nil
+                    applied = true
+                } else if let number = value as? Int, let displayID = CGDirectDisplayID(exactly: number) {
+                    breakOverlayDisplayID = displayID
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "hidesWindowOnStart":
+                applied = flag(value) { hidesWindowOnStart = $0 }
+            case "quitsWhenLastWindowClosed":
+                applied = flag(value) { quitsWhenLastWindowClosed = $0 }
+            case "launchSidebarItem":
+                if let rawValue = value as? String,
+                   let item = MainWindowView.SidebarItem(rawValue: rawValue),
+                   MainWindowView.SidebarItem.visibleItems.contains(item) {
+                    launchSidebarItem = item
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "preventsDisplaySleepDuringWork":
+                applied = flag(value) { preventsDisplaySleepDuringWork = $0 }
+            case "pausesOnScreenLock":
+                applied = flag(value) { pausesOnScreenLock = $0 }
+            case "dailyFocusCapSeconds":
+                applied = nonNegative(value) { dailyFocusCapSeconds = $0 }
+            case "dailySessionGoal":
+                applied = nonNegative(value) { dailySessionGoal = $0 }
+            case "stopsAutoStartAtDailyGoal":
+                applied = flag(value) { stopsAutoStartAtDailyGoal = $0 }
+            case "weeklyFocusGoalSeconds":
+                applied = nonNegative(value) { weeklyFocusGoalSeconds = $0 }
+            case "historyRetentionDays":
+                applied = nonNegative(value) { historyRetentionDays = $0 }
+            case "maxConsecutiveAutoStarts":
+                applied = nonNegative(value) { maxConsecutiveAutoStarts = $0 }
+            case "workHours":
+                if value is NSNull {
+                    workHours = nil
+                    applied = true
+                } else if let dictionary = value as? [String: Any], let hours = WorkHours(dictionary: dictionary) {
+                    workHours = hours
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "weekStartDay":
+                if let day = value as? Int, (1...7).contains(day) {
+                    weekStartDay = day
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "skipCountsTowardCycle":
+                applied = flag(value) { skipCountsTowardCycle = $0 }
+            case "autoStartDelayWorkSeconds":
+                applied = nonNegative(value) { autoStartDelayWorkSeconds = $0 }
+            case "autoStartDelayBreakSeconds":
+                applied = nonNegative(value) { autoStartDelayBreakSeconds = $0 }
+            case "minimumBreakSeconds":
+                applied = nonNegative(value) { minimumBreakSeconds = $0 }
+            case "shortBreakGrowthSeconds":
+                applied = nonNegative(value) { shortBreakGrowthSeconds = $0 }
+            case "countsBreaksAsSessions":
+                applied = flag(value) { countsBreaksAsSessions = $0 }
+            case "focusSoundCrossfadeSeconds":
+                if let musicController, let seconds = value as? Double, (0...5).contains(seconds) {
+                    musicController.focusSoundCrossfadeSeconds = seconds
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "focusSoundRotation":
+                if let musicController, value is NSNull {
+                    musicController.clearFocusSoundRotation()
+                    applied = true
+                } else if let musicController, let dictionary = value as? [String: Any],
+                          let rotation = FocusSoundRotation(dictionary: dictionary) {
+                    musicController.setFocusSoundRotation(rotation.sounds, intervalMinutes: rotation.intervalMinutes)
+                    applied = true
+                } else {
+                    applied = false
+                }
+            case "countdownMutesFocusSound":
+                if let musicController {
+                    applied = flag(value) { musicController.countdownMutesFocusSound = $0 }
+                } else {
+                    applied = false
+                }
+            default:
+                applied = false
+            }
+            if !applied {
+                skippedKeys.append(key)
+            }
+        }
+
+        let importedConfig = DurationConfig(
+            workDuration: work,
+            shortBreakDuration: shortBreak,
+            longBreakDuration: longBreak,
+            longBreakInterval: interval,
+            countdownDuration: countdownDuration
+        )
+        if importedConfig != durationConfig {
+            durationConfig = importedConfig
+            presetSelection = PresetSelection.selection(for: importedConfig)
+        }
+        return skippedKeys.sorted()
     }
 
     private func saveNotificationPreference() {
@@ -363,6 +1727,14 @@ final class AppState: ObservableObject {
         userDefaults.set(reminderPreference.rawValue, forKey: DefaultsKey.reminderPreference)
     }
 
+    private func saveMenuBarIdleTitle() {
+        if let menuBarIdleTitle {
+            userDefaults.set(menuBarIdleTitle, forKey: DefaultsKey.menuBarIdleTitle)
+        } else {
+            userDefaults.removeObject(forKey: DefaultsKey.menuBarIdleTitle)
+        }
+    }
+
     private func savePresetSelection() {
         let value: String
         switch presetSelection {
@@ -411,6 +1783,36 @@ final class AppState: ObservableObject {
         userDefaults.set(data, forKey: DefaultsKey.dailyStats)
     }
 
+    /// Already refreshed, so a streak broken while the app was closed reads 0.
+    private static func loadFocusStreak(from userDefaults: UserDefaults) -> FocusStreak {
+        guard let data = userDefaults.data(forKey: DefaultsKey.focusStreak) else {
+            return FocusStreak()
+        }
+        let decoder = JSONDecoder()
+        decoder.dateDecodingStrategy = .iso8601
+        var streak = (try? decoder.decode(FocusStreak.self, from: data)) ?? FocusStreak()
+        streak.refresh()
+        return streak
+    }
+
+    private func updateFocusStreak(_ update: (inout FocusStreak) -> Void) {
+        var updatedStreak = focusStreak
+        update(&updatedStreak)
+        guard updatedStreak != focusStreak else { return }
+        focusStreak = updatedStreak
+        let encoder = JSONEncoder()
+        encoder.dateEncodingStrategy = .iso8601
+        guard let data = try? encoder.encode(updatedStreak) else { return }
+        userDefaults.set(data, forKey: DefaultsKey.focusStreak)
+    }
+
+    /// Clears both the current and the longest streak.
+    func resetFocusStreak() {
+        updateFocusStreak { streak in
+            streak = FocusStreak()
+        }
+    }
+
     private func requestNotificationAuthorizationIfNeeded() {
         guard notificationDeliveryStyle == .system else { return }
         guard notificationPreference != .off || reminderPreference != .off else { return }
@@ -501,44 +1903,62 @@ final class AppState: ObservableObject {
 
     private func handlePomodoroStateChange(_ state: TimerState) {
         let previousState = lastPomodoroState ?? .idle
+        holdTimerStartedDuringSnooze(state)
+        // Resumed or stopped some other way, so there is nothing left for `resumeAllTimers()` to do.
+        if state != .paused && state != .breakPaused {
+            pomodoroWasRunningBeforeBulkPause = false
+        }
 
         switch state {
         case .running:
             if previousState == .idle {
                 pomodoroDidReachZero = false
                 pomodoroReminderSent = false
-                currentFocusDurationSeconds = durationConfig.workDuration
+                // The engine sets the session length before switching state; it can differ from the
+                // configured work duration for plan sessions and clock-aligned starts.
+                currentFocusDurationSeconds = pomodoro.remainingSeconds
                 refreshDailyStatsForCurrentDay()
             }
             if previousState == .breakRunning || previousState == .breakPaused {
-                currentFocusDurationSeconds = durationConfig.workDuration
+                if pomodoroDidReachZero {
+                    // The break ran out and work started on its own; the engine hasn't reloaded the time yet.
+                    logBreakSessionIfNeeded(completed: true)
+                }
+                pomodoroDidReachZero = false
+                currentFocusDurationSeconds = pomodoro.activeProgramStep?.seconds ?? durationConfig.workDuration
             }
         case .breakRunning:
             if previousState == .running || previousState == .paused {
                 if pomodoroDidReachZero {
-                    sendPomodoroCompletionNotification()
+                    finishCompletedFocusSession()
                     showTransitionPopup(message: transitionMessageForBreakStart())
-                    logFocusSessionIfNeeded()
-                    advanceExecutionPlanAfterCompletedSession()
                 }
                 pomodoroDidReachZero = false
             }
 
             if previousState != .breakPaused {
                 pomodoroReminderSent = false
-                currentBreakDurationSeconds = breakDurationSeconds(for: pomodoro.currentMode)
+                currentBreakDurationSeconds = pomodoro.activeProgramStep?.seconds
+                    ?? breakDurationSeconds(for: pomodoro.currentMode)
             }
             lastBreakMode = pomodoro.currentMode
         case .idle:
             if previousState == .running || previousState == .paused {
-                if !pomodoroDidReachZero {
+                if pomodoroDidReachZero {
+                    // The last step of an interval program, or a work step that wasn't allowed to auto-start.
+                    finishCompletedFocusSession()
+                } else {
                     logFocusSessionIfNeeded(completed: false, interruptionCount: 1)
                 }
+                pomodoroDidReachZero = false
             }
             if previousState == .breakRunning || previousState == .breakPaused {
                 if pomodoroDidReachZero {
                     sendBreakCompletionNotification()
                     logBreakSessionIfNeeded(completed: true)
+                    if isAutoStartLimitReached {
+                        NotificationCenter.default.post(name: .autoStartLimitReached, object: self)
+                    }
                 } else {
                     logBreakSessionIfNeeded(completed: false, interruptionCount: 1)
                 }
@@ -551,6 +1971,72 @@ final class AppState: ObservableObject {
         }
 
         lastPomodoroState = state
+        updateDisplaySleepAssertion()
+    }
+
+    /// Logs a work session that ran to zero and sends its notification.
+    private func finishCompletedFocusSession() {
+        logFocusSessionIfNeeded()
+        if pomodoro.mode == .longBreak {
+            updateDailyStats { stats in
+                stats.logCompletedCycle()
+            }
+        }
+        // Exactly at the goal so the milestone fires once per day; later sessions are ordinary.
+        if dailySessionGoal > 0, dailyStats.completedFocusSessions == dailySessionGoal {
+            sendDailyGoalNotification()
+        } else {
+            sendPomodoroCompletionNotification()
+        }
+        advanceExecutionPlanAfterCompletedSession()
+    }
+
+    /// Program steps of the same kind follow each other without a state change, so
+    /// `handlePomodoroStateChange(_:)` never sees those completions; they are logged here instead.
+    /// Runs before the engine loads the next step, while the finished one is still readable.
+    private func handleProgramStepChange(_ index: Int?) {
+        guard pomodoroDidReachZero, let index, let nextStep = pomodoro.program?.step(at: index) else { return }
+        switch (pomodoro.state, nextStep.mode) {
+        case (.running, .work):
+            finishCompletedFocusSession()
+            currentFocusDurationSeconds = nextStep.seconds
+        case (.breakRunning, .breakTime), (.breakRunning, .longBreak):
+            sendBreakCompletionNotification()
+            logBreakSessionIfNeeded(completed: true)
+            currentBreakDurationSeconds = nextStep.seconds
+        default:
+            return
+        }
+        pomodoroDidReachZero = false
+        pomodoroReminderSent = false
+    }
+
+    private func handleScreenLock() {
+        guard pausesOnScreenLock, pomodoro.state == .running || pomodoro.state == .breakRunning else { return }
+        pomodoro.pause()
+        isPomodoroPausedByLock = true
+    }
+
+    /// Resumes only if the Pomodoro is still paused where the lock left it.
+    private func handleScreenUnlock() {
+        guard isPomodoroPausedByLock else { return }
+        isPomodoroPausedByLock = false
+        if pomodoro.state == .paused || pomodoro.state == .breakPaused {
+            pomodoro.resume()
+        }
+    }
+
+    private func updateDisplaySleepAssertion() {
+        if preventsDisplaySleepDuringWork, pomodoro.state == .running {
+            displaySleepAssertion.acquire(reason: "Pomodoro work session in progress")
+        } else {
+            displaySleepAssertion.release()
+        }
+    }
+
+    /// Lets the display sleep again, e.g. when the app quits mid-session.
+    func releaseDisplaySleepAssertion() {
+        displaySleepAssertion.release()
     }
 
     private func advanceExecutionPlanAfterCompletedSession() {
@@ -593,6 +2079,10 @@ final class AppState: ObservableObject {
 
     private func handleCountdownStateChange(_ state: TimerState) {
         let previousState = lastCountdownState ?? .idle
+        holdTimerStartedDuringSnooze(state)
+        if state != .paused {
+            countdownWasRunningBeforeBulkPause = false
+        }
 
         switch state {
         case .running:
@@ -618,14 +2108,16 @@ final class AppState: ObservableObject {
         let l10n = LocalizationManager.shared
         let reminderLeadTime = reminderPreference.leadTimeSeconds
         guard reminderLeadTime > 0, !pomodoroReminderSent else { return }
-        guard remainingSeconds == reminderLeadTime else { return }
+        // `<=` so a jump past the lead time, e.g. from removing a minute, still sends it.
+        guard remainingSeconds <= reminderLeadTime else { return }
+        // A session no longer than the lead time would get it the moment it starts.
+        guard pomodoro.sessionDurationSeconds > reminderLeadTime else { return }
         guard pomodoro.state == .running || pomodoro.state == .breakRunning else { return }
 
         let title = pomodoro.currentMode == .work
             ? l10n.text("notification.pomodoro_ending_soon")
             : l10n.text("notification.break_ending_soon")
-        let body = l10n.text("notification.one_minute_remaining")
-        sendNotification(title: title, body: body)
+        sendNotification(title: title, body: reminderPreference.remainingMessage)
         pomodoroReminderSent = true
     }
 
@@ -633,12 +2125,13 @@ final class AppState: ObservableObject {
         let l10n = LocalizationManager.shared
         let reminderLeadTime = reminderPreference.leadTimeSeconds
         guard reminderLeadTime > 0, !countdownReminderSent else { return }
-        guard remainingSeconds == reminderLeadTime else { return }
+        guard remainingSeconds <= reminderLeadTime else { return }
+        guard countdown.sessionDurationSeconds > reminderLeadTime else { return }
         guard countdown.state == .running else { return }
 
         sendNotification(
             title: l10n.text("notification.countdown_ending_soon"),
-            body: l10n.text("notification.one_minute_remaining")
+            body: reminderPreference.remainingMessage
         )
         countdownReminderSent = true
     }
@@ -647,7 +2140,22 @@ final class AppState: ObservableObject {
         let l10n = LocalizationManager.shared
         sendNotification(
             title: decoratedTitle(l10n.text("notification.focus_complete"), emoji: "🍅"),
-            body: l10n.text("notification.time_for_break")
+            // The engine has already switched to the break, so `remainingSeconds` is its length.
+            body: completionNotificationBody(
+                default: l10n.text("notification.time_for_break"),
+                finishedMode: .work,
+                nextSeconds: pomodoro.remainingSeconds
+            ),
+            isCompletion: true
+        )
+    }
+
+    private func sendDailyGoalNotification() {
+        let l10n = LocalizationManager.shared
+        sendNotification(
+            title: decoratedTitle(l10n.text("notification.daily_goal_reached"), emoji: "🎉"),
+            body: l10n.format("notification.daily_goal_body", dailySessionGoal),
+            isCompletion: true
         )
     }
 
@@ -662,17 +2170,75 @@ final class AppState: ObservableObject {
         case .work, .idle, nil:
             title = decoratedTitle(l10n.text("notification.break_complete"), emoji: "☕️")
         }
-        sendNotification(title: title, body: l10n.text("notification.ready_to_focus_again"))
+        let body = completionNotificationBody(
+            default: l10n.text("notification.ready_to_focus_again"),
+            finishedMode: lastBreakMode ?? .break,
+            nextSeconds: durationConfig.workDuration
+        )
+        sendNotification(title: title, body: body, isCompletion: true)
+    }
+
+    private func completionNotificationBody(
+        default defaultBody: String,
+        finishedMode: PomodoroTimerEngine.CurrentMode,
+        nextSeconds: Int
+    ) -> String {
+        guard let notificationBodyTemplate else { return defaultBody }
+        let l10n = LocalizationManager.shared
+        let modeName: String
+        switch finishedMode {
+        case .work, .idle:
+            modeName = l10n.text("timer.mode.pomodoro")
+        case .break:
+            modeName = l10n.text("timer.mode.break")
+        case .longBreak:
+            modeName = l10n.text("timer.mode.long_break")
+        }
+        return notificationBodyTemplate.render(
+            mode: modeName,
+            count: dailyStats.completedFocusSessions,
+            nextMinutes: nextSeconds / 60
+        )
     }
 
     private func sendCountdownCompletionNotification() {
         let l10n = LocalizationManager.shared
+        // Same glyph as the countdown status item so the banner reads as the countdown's.
         sendNotification(
-            title: decoratedTitle(l10n.text("notification.countdown_complete"), emoji: "⏳"),
-            body: l10n.text("notification.time_is_up")
+            title: decoratedTitle(l10n.text("notification.countdown_complete"), emoji: "⏱"),
+            body: l10n.text("notification.time_is_up"),
+            isCompletion: true
         )
     }
 
+    /// One-line status for chat presence fields, e.g. "🍅 Focusing — 18:42 left (session 2/4)".
+    /// Truncated to Slack's 100-character status limit.
+    func sessionShareText() -> String {
+        let l10n = LocalizationManager.shared
+        let text: String
+        switch pomodoro.state {
+        case .running, .paused:
+            let interval = max(1, durationConfig.longBreakInterval)
+            let session = pomodoro.completedWorkSessions % interval + 1
+            text = decoratedTitle(
+                l10n.format("share.focusing", Self.shareClock(pomodoro.remainingSeconds), session, interval),
+                emoji: "🍅"
+            )
+        case .breakRunning, .breakPaused:
+            text = decoratedTitle(l10n.text("share.on_break"), emoji: "☕️")
+        case .idle:
+            text = decoratedTitle(l10n.text("share.idle"), emoji: "🍅")
+        }
+        return String(text.prefix(Self.shareTextLimit))
+    }
+
+    private static let shareTextLimit = 100
+
+    private static func shareClock(_ seconds: Int) -> String {
+        let clampedSeconds = max(0, seconds)
+        return String(format: "%02d:%02d", clampedSeconds / 60, clampedSeconds % 60)
+    }
+
     private func decoratedTitle(_ title: String, emoji: String?) -> String {
         guard let emoji else { return title }
         return "\(emoji) \(title)"
@@ -702,9 +2268,55 @@ final class AppState: ObservableObject {
         }
     }
 
-    private func sendNotification(title: String, body: String) {
-        guard notificationPreference != .off else { return }
-        if notificationDeliveryStyle == .inApp {
+    /// At most one completion notification is delivered per `completionNotificationInterval`; any
+    /// that arrive in between are held and delivered together once it has passed.
+    private func sendNotification(title: String, body: String, isCompletion: Bool = false) {
+        guard notificationPreference != .off, snoozedUntil == nil else { return }
+        guard isCompletion else {
+            deliverNotification(title: title, body: body, isCompletion: false)
+            return
+        }
+        let now = ProcessInfo.processInfo.systemUptime
+        if let lastCompletionNotificationAt,
+           now - lastCompletionNotificationAt < Self.completionNotificationInterval {
+            heldCompletionNotifications.append((title, body))
+            if heldCompletionNotifications.count == 1 {
+                let delay = lastCompletionNotificationAt + Self.completionNotificationInterval - now
+                DispatchQueue.main.asyncAfter(deadline: .now() + delay) { [weak self] in
+                    self?.deliverHeldCompletionNotifications()
+                }
+            }
+            return
+        }
+        lastCompletionNotificationAt = now
+        deliverNotification(title: title, body: body, isCompletion: true)
+    }
+
+    private func deliverHeldCompletionNotifications() {
+        let held = heldCompletionNotifications
+        heldCompletionNotifications.removeAll()
+        guard notificationPreference != .off, let first = held.first else { return }
+        lastCompletionNotificationAt = ProcessInfo.processInfo.systemUptime
+        if held.count == 1 {
+            deliverNotification(title: first.title, body: first.body, isCompletion: true)
+        } else {
+            let l10n = LocalizationManager.shared
+            deliverNotification(
+                title: l10n.format("notification.coalesced.title", held.count),
+                body: l10n.text("notification.coalesced.body"),
+                isCompletion: true
+            )
+        }
+    }
+
+    private func deliverNotification(title: String, body: String, isCompletion: Bool) {
+        let customChimeURL = isCompletion && notificationPreference == .sound
+            ? completionSoundPath.map { URL(fileURLWithPath: $0) }
+            : nil
+        if let customChimeURL {
+            completionSoundPlayer.play(contentsOf: customChimeURL)
+        }
+        if notificationDeliveryStyle == .inApp || (notificationDeliveryStyle == .automatic && isMainWindowFocused) {
             showNotificationPopup(title: title, body: body)
             return
         }
@@ -718,12 +2330,17 @@ final class AppState: ObservableObject {
             let content = UNMutableNotificationContent()
             content.title = title
             content.body = body
-            if self.notificationPreference == .sound {
+            if self.notificationPreference == .sound, customChimeURL == nil {
                 content.sound = .default
             }
+            // Reusing the identifier replaces a delivered alert instead of stacking another.
+            let group = self.notificationGroup
+            if let group {
+                content.threadIdentifier = group
+            }
             let trigger = UNTimeIntervalNotificationTrigger(timeInterval: 1, repeats: false)
             let request = UNNotificationRequest(
-                identifier: UUID().uuidString,
+                identifier: group ?? UUID().uuidString,
                 content: content,
                 trigger: trigger
             )
@@ -731,6 +2348,17 @@ final class AppState: ObservableObject {
         }
     }
 
+    /// True while the user is looking at the app: it is frontmost and its main window is key.
+    private var isMainWindowFocused: Bool {
+        guard Thread.isMainThread else { return false }
+        return MainActor.assumeIsolated {
+            guard NSApplication.shared.isActive, let keyWindow = NSApplication.shared.keyWindow else { return false }
+            // SwiftUI derives scene window identifiers from the scene ID.
+            return keyWindow.isVisible
+                && keyWindow.identifier?.rawValue.hasPrefix(OrchestranaApp.mainWindowID) == true
+        }
+    }
+
     private func showNotificationPopup(title: String, body: String) {
         let popup = NotificationPopup(id: UUID(), title: title, body: body)
         DispatchQueue.main.async {
@@ -743,10 +2371,23 @@ final class AppState: ObservableObject {
         }
     }
 
+    /// Timers count ticks rather than comparing against the wall clock, so a clock change leaves
+    /// them alone. Only date-dependent state needs refreshing.
+    private func handleSystemClockChange() {
+        refreshDailyStatsForCurrentDay()
+        armScheduledQuit()
+        armSnoozeEnd()
+        armMenuBarTitleOverrideExpiry()
+        NotificationCenter.default.post(name: .clockAdjusted, object: self)
+    }
+
     private func refreshDailyStatsForCurrentDay() {
         updateDailyStats { stats in
             stats.ensureCurrentDay()
         }
+        updateFocusStreak { streak in
+            streak.refresh()
+        }
     }
 
     private func logFocusSessionIfNeeded(completed: Bool = true, interruptionCount: Int? = nil) {
@@ -756,11 +2397,18 @@ final class AppState: ObservableObject {
             completed: completed
         )
         guard durationSeconds > 0 else { return }
+        let pausedSeconds = pomodoro.currentSessionPausedSeconds
         let endTime = Date()
-        let startTime = endTime.addingTimeInterval(TimeInterval(-durationSeconds))
+        let startTime = endTime.addingTimeInterval(TimeInterval(-(durationSeconds + pausedSeconds)))
         updateDailyStats { stats in
             stats.logFocusSession(durationSeconds: durationSeconds, completed: completed)
         }
+        if completed {
+            completedFocusSessionsThisLaunch += 1
+            updateFocusStreak { streak in
+                streak.logCompletedFocusSession()
+            }
+        }
         // Local-only session record to power insights; no server or cloud dependency.
         appendSessionRecord(
             startTime: startTime,
@@ -769,7 +2417,8 @@ final class AppState: ObservableObject {
             taskId: nil,
             sessionType: .focus,
             completed: completed,
-            interruptionCount: interruptionCount
+            interruptionCount: interruptionCount,
+            pausedSeconds: pausedSeconds
         )
     }
 
@@ -780,8 +2429,9 @@ final class AppState: ObservableObject {
             completed: completed
         )
         guard durationSeconds > 0 else { return }
+        let pausedSeconds = pomodoro.currentSessionPausedSeconds
         let endTime = Date()
-        let startTime = endTime.addingTimeInterval(TimeInterval(-durationSeconds))
+        let startTime = endTime.addingTimeInterval(TimeInterval(-(durationSeconds + pausedSeconds)))
         updateDailyStats { stats in
             stats.logBreakSession(durationSeconds: durationSeconds, completed: completed)
         }
@@ -792,7 +2442,8 @@ final class AppState: ObservableObject {
             taskId: nil,
             sessionType: .break,
             completed: completed,
-            interruptionCount: interruptionCount
+            interruptionCount: interruptionCount,
+            pausedSeconds: pausedSeconds
         )
     }
 
@@ -803,17 +2454,19 @@ final class AppState: ObservableObject {
         taskId: UUID?,
         sessionType: SessionType,
         completed: Bool,
-        interruptionCount: Int?
+        interruptionCount: Int?,
+        pausedSeconds: Int?
     ) {
         Task { @MainActor in
-            SessionRecordStore.shared.appendRecord(
+            self.sessionRecordStore.appendRecord(
                 startTime: startTime,
                 endTime: endTime,
                 durationSeconds: durationSeconds,
                 taskId: taskId,
                 sessionType: sessionType,
                 completed: completed,
-                interruptionCount: interruptionCount
+                interruptionCount: interruptionCount,
+                pausedSeconds: pausedSeconds
             )
         }
     }
@@ -840,7 +2493,7 @@ final class AppState: ObservableObject {
     private func breakDurationSeconds(for mode: PomodoroTimerEngine.CurrentMode) -> Int? {
         switch mode {
         case .break:
-            return durationConfig.shortBreakDuration
+            return pomodoro.currentShortBreakSeconds
         case .longBreak:
             return durationConfig.longBreakDuration
         case .work, .idle:
@@ -861,3 +2514,18 @@ extension AppState {
         let body: String
     }
 }
+
+// MARK: - Timer lifecycle events
+extension Notification.Name {
+    /// Posted when a break ends at idle because `maxConsecutiveAutoStarts` was reached.
+    static let autoStartLimitReached = Notification.Name("autoStartLimitReached")
+    /// Posted when the user starts the Pomodoro or countdown, not when a cycle auto-advances.
+    static let timerDidStart = Notification.Name("timerDidStart")
+    /// Posted by `snooze(until:hidesWindow:)`. `userInfo[AppState.snoozeHidesWindowKey]` is a `Bool`.
+    static let snoozeDidStart = Notification.Name("snoozeDidStart")
+    /// Posted when a snooze ends, whether it ran out or was ended early.
+    static let snoozeDidEnd = Notification.Name("snoozeDidEnd")
+    /// Posted after the system clock is changed, e.g. by hand or an NTP correction, so anything
+    /// showing wall-clock times such as the finish time can refresh.
+    static let clockAdjusted = Notification.Name("clockAdjusted")
+}
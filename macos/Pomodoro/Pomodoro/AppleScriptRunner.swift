@@ -8,6 +8,11 @@
 import AppKit
 import Foundation
 
+struct AppleScriptFailure: Error {
+    let number: Int
+    let message: String
+}
+
 enum AppleScriptRunner {
     static func run(_ script: String) async -> NSAppleEventDescriptor? {
         await withCheckedContinuation { continuation in
@@ -19,6 +24,31 @@ enum AppleScriptRunner {
             }
         }
     }
+
+    /// Like `run(_:)`, but keeps the AppleScript error so callers can tell a permission denial
+    /// apart from, say, a target app that is not running.
+    static func runReportingError(_ script: String) async -> Result<NSAppleEventDescriptor, AppleScriptFailure> {
+        await withCheckedContinuation { continuation in
+            DispatchQueue.global(qos: .userInitiated).async {
+                var error: NSDictionary?
+                if let result = NSAppleScript(source: script)?.executeAndReturnError(&error) {
+                    continuation.resume(returning: .success(result))
+                    return
+                }
+                let failure = AppleScriptFailure(
+                    number: error?[NSAppleScript.errorNumber] as? Int ?? 0,
+                    message: error?[NSAppleScript.errorMessage] as? String ?? ""
+                )
+                continuation.resume(returning: .failure(failure))
+            }
+        }
+    }
+
+    static func compiles(_ script: String) -> Bool {
+        guard let appleScript = NSAppleScript(source: script) else { return false }
+        var error: NSDictionary?
+        return appleScript.compileAndReturnError(&error)
+    }
 }
 
 extension NSAppleEventDescriptor {
@@ -0,0 +1,110 @@
+import AppKit
+import Combine
+import SwiftUI
+
+/// Covers the chosen display with a gentle "Look away" reminder while a break runs, when
+/// `AppState.showsBreakOverlay` is on. Dismissing it hides it until the next break.
+@MainActor
+final class BreakOverlayController {
+    private unowned let appState: AppState
+    private var window: NSWindow?
+    private var cancellables: Set<AnyCancellable> = []
+
+    init(appState: AppState) {
+        self.appState = appState
+
+        appState.pomodoro.$state
+            .removeDuplicates()
+            .scan((TimerState.idle, TimerState.idle)) { ($0.1, $1) }
+            .receive(on: RunLoop.main)
+            .sink { [weak self] previous, current in
+                self?.handleStateChange(from: previous, to: current)
+            }
+            .store(in: &cancellables)
+
+        appState.$showsBreakOverlay
+            .dropFirst()
+            .filter { !$0 }
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.dismiss()
+            }
+            .store(in: &cancellables)
+    }
+
+    func dismiss() {
+        window?.orderOut(nil)
+        window = nil
+    }
+
+    private func handleStateChange(from previous: TimerState, to current: TimerState) {
+        switch current {
+        case .breakRunning:
+            // Only a break that just began; resuming a paused break leaves a dismissed overlay closed.
+            if previous != .breakPaused, appState.showsBreakOverlay {
+                show()
+            }
+        case .breakPaused:
+            break
+        case .idle, .running, .paused:
+            dismiss()
+        }
+    }
+
+    private func show() {
+        let screens = NSScreen.screens
+        guard let screen = screens.first(where: { $0.displayID == appState.breakOverlayDisplayID }) ?? screens.first else {
+            return
+        }
+        dismiss()
+        let window = BreakOverlayWindow(
+            contentRect: screen.frame,
+            styleMask: [.borderless],
+            backing: .buffered,
+            defer: false
+        )
+        window.isReleasedWhenClosed = false
+        window.isOpaque = false
+        window.backgroundColor = .clear
+        window.hasShadow = false
+        window.level = .floating
+        window.collectionBehavior = [.canJoinAllSpaces, .fullScreenAuxiliary]
+        window.contentView = NSHostingView(
+            rootView: BreakOverlayView(pomodoro: appState.pomodoro) { [weak self] in
+                self?.dismiss()
+            }
+        )
+        window.setFrame(screen.frame, display: true)
+        window.makeKeyAndOrderFront(nil)
+        self.window = window
+    }
+}
+
+/// Borderless windows can't become key by default, which would leave Escape without effect.
+private final class BreakOverlayWindow: NSWindow {
+    override var canBecomeKey: Bool { true }
+}
+
+private struct BreakOverlayView: View {
+    @ObservedObject var pomodoro: PomodoroTimerEngine
+    let dismiss: () -> Void
+
+    var body: some View {
+        ZStack {
+            Color.black.opacity(0.55)
+                .ignoresSafeArea()
+            VStack(spacing: 20) {
+                Text(LocalizationManager.shared.format("break_overlay.message", clock))
+                    .font(.system(size: 44, weight: .semibold, design: .rounded).monospacedDigit())
+                    .foregroundStyle(.white)
+                Button(LocalizationManager.shared.text("break_overlay.dismiss"), action: dismiss)
+                    .keyboardShortcut(.cancelAction)
+                    .buttonStyle(.bordered)
+            }
+        }
+    }
+
+    private var clock: String {
+        String(format: "%d:%02d", pomodoro.remainingSeconds / 60, pomodoro.remainingSeconds % 60)
+    }
+}
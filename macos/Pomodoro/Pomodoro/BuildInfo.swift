@@ -0,0 +1,50 @@
+import Foundation
+
+/// What this binary is and how it was built, for bug reports and for hiding views that depend on
+/// compile-time options. Cheap to create: reads the main bundle and compile-time conditions only.
+struct BuildInfo: Equatable {
+    let version: String
+    let build: String
+    /// Nil unless the build stamps `GitCommitSHA` into Info.plist.
+    let gitSHA: String?
+    /// Active compilation conditions this app checks with `#if`, e.g. "DEBUG".
+    let features: [String]
+    let os: String
+    let arch: String
+
+    static let current = BuildInfo(bundle: .main)
+
+    init(bundle: Bundle) {
+        let info = bundle.infoDictionary ?? [:]
+        version = info["CFBundleShortVersionString"] as? String ?? "unknown"
+        build = info["CFBundleVersion"] as? String ?? "unknown"
+        gitSHA = (info["GitCommitSHA"] as? String).flatMap { $0.isEmpty ? nil : $0 }
+        var features: [String] = []
+#if DEBUG
+        features.append("DEBUG")
+#endif
+        self.features = features
+        let osVersion = ProcessInfo.processInfo.operatingSystemVersion
+        os = "macOS \(osVersion.majorVersion).\(osVersion.minorVersion).\(osVersion.patchVersion)"
+#if arch(arm64)
+        arch = "arm64"
+#elseif arch(x86_64)
+        arch = "x86_64"
+#else
+        arch = "unknown"
+#endif
+    }
+
+    /// One line for pasting into a bug report.
+    var summary: String {
+        var parts = ["Orchestrana \(version) (\(build))"]
+        if let gitSHA {
+            parts.append(gitSHA)
+        }
+        parts.append("\(os) \(arch)")
+        if !features.isEmpty {
+            parts.append(features.joined(separator: ", "))
+        }
+        return parts.joined(separator: " · ")
+    }
+}
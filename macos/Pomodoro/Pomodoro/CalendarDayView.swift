@@ -102,7 +102,7 @@ struct CalendarDayView: View {
     }
     
     private func formatEventTime(_ event: EKEvent) -> String {
-        Self.timeFormatter.locale = localizationManager.effectiveLocale
+        localizationManager.configureTimeFormatter(Self.timeFormatter)
         let start = Self.timeFormatter.string(from: event.startDate)
         let end = Self.timeFormatter.string(from: event.endDate)
         return "\(start) - \(end)"
@@ -980,7 +980,7 @@ struct CalendarView: View {
         if event.isAllDay {
             return localizationManager.text("calendar.all_day")
         }
-        Self.eventTimeFormatter.locale = localizationManager.effectiveLocale
+        localizationManager.configureTimeFormatter(Self.eventTimeFormatter)
         let start = Self.eventTimeFormatter.string(from: event.startDate)
         let end = Self.eventTimeFormatter.string(from: event.endDate)
         return "\(start) - \(end)"
@@ -1549,7 +1549,7 @@ struct CalendarView: View {
 
     private func formattedTaskDue(item: TodoItem, due: Date) -> String {
         Self.shortDayFormatter.locale = localizationManager.effectiveLocale
-        Self.eventTimeFormatter.locale = localizationManager.effectiveLocale
+        localizationManager.configureTimeFormatter(Self.eventTimeFormatter)
         let day = Self.shortDayFormatter.string(from: due)
         let suffix = item.hasDueTime ? " • \(Self.eventTimeFormatter.string(from: due))" : ""
         return day + suffix
@@ -1596,7 +1596,7 @@ struct CalendarView: View {
         guard let start = item.startDate else {
             return localizationManager.text("calendar.no_due_time")
         }
-        Self.eventTimeFormatter.locale = localizationManager.effectiveLocale
+        localizationManager.configureTimeFormatter(Self.eventTimeFormatter)
         let startText = Self.eventTimeFormatter.string(from: start)
         guard let end = item.endDate else {
             return startText
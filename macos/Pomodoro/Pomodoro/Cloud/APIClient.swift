@@ -170,6 +170,18 @@ final class APIClient {
         }
         return CloudEndpointResolver.functionsURL(path: "")!
     }
+
+    /// Sends an unauthenticated HEAD request to the backend root. Any HTTP response, error statuses
+    /// included, counts as reachable; only a transport failure or missing configuration throws.
+    static func checkReachability() async throws {
+        guard let url = CloudEndpointResolver.explicitURL(for: "POMODORO_CLOUD_BASE_URL")
+            ?? CloudEndpointResolver.functionsURL(path: "") else {
+            throw APIError.invalidEndpoint
+        }
+        var request = URLRequest(url: url, timeoutInterval: 10)
+        request.httpMethod = "HEAD"
+        _ = try await URLSession.shared.data(for: request)
+    }
 }
 
 final class AccountDeletionAPIClient {
@@ -0,0 +1,58 @@
+import AppKit
+import AVFoundation
+import Foundation
+
+enum CompletionSoundError: LocalizedError {
+    case fileNotFound
+    case unsupportedFormat
+
+    var errorDescription: String? {
+        switch self {
+        case .fileNotFound:
+            return LocalizationManager.shared.text("notification.sound.error.not_found")
+        case .unsupportedFormat:
+            return LocalizationManager.shared.text("notification.sound.error.unsupported")
+        }
+    }
+}
+
+/// Plays the user's chosen completion chime in place of the default notification sound.
+final class CompletionSoundPlayer {
+    private var activeSound: NSSound?
+
+    /// Throws unless `url` points at an existing file the audio decoder can open.
+    static func validate(_ url: URL) throws {
+        guard FileManager.default.fileExists(atPath: url.path) else {
+            throw CompletionSoundError.fileNotFound
+        }
+        do {
+            _ = try AVAudioFile(forReading: url)
+        } catch {
+            throw CompletionSoundError.unsupportedFormat
+        }
+    }
+
+    /// Security-scoped bookmark so the sandboxed app can reopen the file after relaunch.
+    static func bookmark(for url: URL) -> Data? {
+        try? url.bookmarkData(options: .withSecurityScope, includingResourceValuesForKeys: nil, relativeTo: nil)
+    }
+
+    static func resolveBookmark(_ bookmarkData: Data) -> URL? {
+        var isStale = false
+        guard let url = try? URL(
+            resolvingBookmarkData: bookmarkData,
+            options: [.withSecurityScope],
+            relativeTo: nil,
+            bookmarkDataIsStale: &isStale
+        ) else { return nil }
+        _ = url.startAccessingSecurityScopedResource()
+        return url
+    }
+
+    func play(contentsOf url: URL) {
+        activeSound?.stop()
+        // NSSound stops when released, so keep a reference until the next chime.
+        activeSound = NSSound(contentsOf: url, byReference: true)
+        activeSound?.play()
+    }
+}
@@ -0,0 +1,44 @@
+import Combine
+import Foundation
+
+/// Pauses the focus sound while a countdown runs when `MusicController.countdownMutesFocusSound`
+/// is on, and resumes it once the countdown finishes or is reset.
+@MainActor
+final class CountdownFocusSoundMuteController {
+    private unowned let appState: AppState
+    private let musicController: MusicController
+    private var cancellables: Set<AnyCancellable> = []
+    private var mutedFocusSound = false
+
+    init(appState: AppState, musicController: MusicController) {
+        self.appState = appState
+        self.musicController = musicController
+
+        appState.countdown.$state
+            .removeDuplicates()
+            .sink { [weak self] state in
+                self?.handleCountdownStateChange(state)
+            }
+            .store(in: &cancellables)
+    }
+
+    private func handleCountdownStateChange(_ state: TimerState) {
+        switch state {
+        case .running:
+            guard !mutedFocusSound,
+                  musicController.countdownMutesFocusSound,
+                  musicController.effectiveFocusSound != .off else { return }
+            musicController.pause()
+            mutedFocusSound = true
+        case .idle:
+            guard mutedFocusSound else { return }
+            mutedFocusSound = false
+            // Leave it alone if the user started something else during the countdown.
+            if musicController.playbackState == .paused {
+                musicController.play()
+            }
+        case .paused, .breakRunning, .breakPaused:
+            break
+        }
+    }
+}
@@ -11,10 +11,17 @@ import Foundation
 final class CountdownTimerEngine: ObservableObject {
     @Published private(set) var state: TimerState = .idle
     @Published private(set) var remainingSeconds: Int
+    /// Length the countdown started with; the configured duration while idle.
+    private(set) var sessionDurationSeconds: Int
+
+    var elapsedSeconds: Int {
+        max(0, sessionDurationSeconds - remainingSeconds)
+    }
 
     private var durationConfig: DurationConfig
     private let durationProvider: (DurationConfig) -> Int
     private var timer: Timer?
+    private(set) var tickStats = TickPerformanceStats()
 
     init(
         durationConfig: DurationConfig = .standard,
@@ -24,6 +31,7 @@ final class CountdownTimerEngine: ObservableObject {
         self.durationProvider = durationProvider
         let resolvedDuration = durationProvider(durationConfig)
         self.remainingSeconds = resolvedDuration
+        self.sessionDurationSeconds = resolvedDuration
     }
 
     func updateConfiguration(durationConfig: DurationConfig) {
@@ -31,12 +39,14 @@ final class CountdownTimerEngine: ObservableObject {
 
         if state == .idle {
             remainingSeconds = duration
+            sessionDurationSeconds = duration
         }
     }
 
     func start() {
         guard state == .idle else { return }
         remainingSeconds = duration
+        sessionDurationSeconds = duration
         state = .running
         startTimer()
     }
@@ -65,6 +75,24 @@ final class CountdownTimerEngine: ObservableObject {
         stopTimer()
         state = .idle
         remainingSeconds = duration
+        sessionDurationSeconds = duration
+    }
+
+    /// Jumps an active countdown to `seconds` remaining without touching the configured
+    /// duration or the running state. Values are clamped to the duration; zero completes it.
+    /// The session length grows if needed so elapsed time never goes negative.
+    func setRemaining(_ seconds: Int) {
+        switch state {
+        case .running, .paused:
+            break
+        case .idle, .breakRunning, .breakPaused:
+            return
+        }
+        remainingSeconds = min(max(0, seconds), duration)
+        sessionDurationSeconds = max(sessionDurationSeconds, remainingSeconds)
+        if remainingSeconds == 0 {
+            complete()
+        }
     }
 
     private func startTimer() {
@@ -80,12 +108,16 @@ final class CountdownTimerEngine: ObservableObject {
     }
 
     private func tick() {
+        let tickStart = DispatchTime.now()
+        defer { tickStats.recordTick(startedAt: tickStart) }
         guard remainingSeconds > 0 else {
             complete()
             return
         }
 
+        let emitStart = DispatchTime.now()
         remainingSeconds -= 1
+        tickStats.recordEmit(startedAt: emitStart)
 
         if remainingSeconds == 0 {
             complete()
@@ -96,6 +128,7 @@ final class CountdownTimerEngine: ObservableObject {
         stopTimer()
         state = .idle
         remainingSeconds = duration
+        sessionDurationSeconds = duration
     }
 
     private var duration: Int {
@@ -0,0 +1,89 @@
+//
+//  CustomScriptProvider.swift
+//  Pomodoro
+//
+
+import AppKit
+import Foundation
+
+/// AppleScript snippets that drive a player the app has no built-in provider for.
+struct CustomPlayerScripts: Codable, Equatable {
+    let name: String
+    let playPauseScript: String
+    let nextScript: String
+    let previousScript: String
+    /// Must return `{isRunning, isPlaying, title, artist}`, the same shape the Spotify provider reads.
+    let metadataScript: String
+}
+
+enum CustomPlayerError: LocalizedError {
+    case missingName
+    case scriptDoesNotCompile(String)
+    case metadataScriptFailed
+
+    var errorDescription: String? {
+        switch self {
+        case .missingName:
+            return LocalizationManager.shared.text("audio.custom_player.error.missing_name")
+        case .scriptDoesNotCompile(let scriptName):
+            return LocalizationManager.shared.format("audio.custom_player.error.compile", scriptName)
+        case .metadataScriptFailed:
+            return LocalizationManager.shared.text("audio.custom_player.error.metadata")
+        }
+    }
+}
+
+final class CustomScriptProvider: NowPlayingProvider {
+    let scripts: CustomPlayerScripts
+
+    var sourceName: String { scripts.name }
+
+    init(scripts: CustomPlayerScripts) {
+        self.scripts = scripts
+    }
+
+    /// Compiles every script and runs the metadata script once. The transport scripts are only
+    /// compiled, since running them would change playback.
+    static func validate(_ scripts: CustomPlayerScripts) async throws {
+        guard !scripts.name.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty else {
+            throw CustomPlayerError.missingName
+        }
+        let namedScripts = [
+            ("play/pause", scripts.playPauseScript),
+            ("next", scripts.nextScript),
+            ("previous", scripts.previousScript),
+            ("metadata", scripts.metadataScript)
+        ]
+        for (scriptName, source) in namedScripts where !AppleScriptRunner.compiles(source) {
+            throw CustomPlayerError.scriptDoesNotCompile(scriptName)
+        }
+        guard await AppleScriptRunner.run(scripts.metadataScript) != nil else {
+            throw CustomPlayerError.metadataScriptFailed
+        }
+    }
+
+    func fetchState() async -> NowPlayingProviderState {
+        guard let result = await AppleScriptRunner.run(scripts.metadataScript) else {
+            return NowPlayingProviderState(isRunning: false, isPlaying: false, title: "", artist: "", artwork: nil)
+        }
+        return NowPlayingProviderState(
+            isRunning: result.descriptor(at: 1)?.booleanValue ?? false,
+            isPlaying: result.descriptor(at: 2)?.booleanValue ?? false,
+            title: result.descriptor(at: 3)?.stringValue ?? "",
+            artist: result.descriptor(at: 4)?.stringValue ?? "",
+            artwork: nil
+        )
+    }
+
+    func playPause() async {
+        _ = await AppleScriptRunner.run(scripts.playPauseScript)
+    }
+
+    func nextTrack() async {
+        _ = await AppleScriptRunner.run(scripts.nextScript)
+    }
+
+    func previousTrack() async {
+        _ = await AppleScriptRunner.run(scripts.previousScript)
+    }
+}
@@ -11,10 +11,15 @@ struct DailyStats: Codable, Equatable {
     private(set) var dayStart: Date
     private(set) var totalFocusSeconds: Int
     private(set) var totalBreakSeconds: Int
+    /// Every completed session, breaks included.
     private(set) var completedSessions: Int
+    /// Completed work sessions only, regardless of how breaks are counted elsewhere.
+    private(set) var completedFocusSessions: Int
     private(set) var totalSessions: Int
     private(set) var totalSessionSeconds: Int
     private(set) var longestSessionSeconds: Int
+    /// Times a work session completed straight into a long break.
+    private(set) var completedCycles: Int
 
     init(date: Date = Date(), calendar: Calendar = .current) {
         let startOfDay = calendar.startOfDay(for: date)
@@ -22,9 +27,11 @@ struct DailyStats: Codable, Equatable {
         self.totalFocusSeconds = 0
         self.totalBreakSeconds = 0
         self.completedSessions = 0
+        self.completedFocusSessions = 0
         self.totalSessions = 0
         self.totalSessionSeconds = 0
         self.longestSessionSeconds = 0
+        self.completedCycles = 0
     }
 
     mutating func reset(for date: Date = Date(), calendar: Calendar = .current) {
@@ -32,9 +39,11 @@ struct DailyStats: Codable, Equatable {
         totalFocusSeconds = 0
         totalBreakSeconds = 0
         completedSessions = 0
+        completedFocusSessions = 0
         totalSessions = 0
         totalSessionSeconds = 0
         longestSessionSeconds = 0
+        completedCycles = 0
     }
 
     mutating func ensureCurrentDay(_ date: Date = Date(), calendar: Calendar = .current) {
@@ -48,6 +57,11 @@ struct DailyStats: Codable, Equatable {
         return Double(totalSessionSeconds) / Double(totalSessions)
     }
 
+    /// The "sessions" figure shown to the user, with or without completed breaks.
+    func completedSessionCount(includingBreaks: Bool) -> Int {
+        includingBreaks ? completedSessions : completedFocusSessions
+    }
+
     var completionRate: Double {
         guard totalSessions > 0 else { return 0 }
         return Double(completedSessions) / Double(totalSessions)
@@ -75,6 +89,9 @@ struct DailyStats: Codable, Equatable {
         longestSessionSeconds = max(longestSessionSeconds, durationSeconds)
         if completed {
             completedSessions += 1
+            if type == .focus {
+                completedFocusSessions += 1
+            }
         }
     }
 
@@ -108,16 +125,23 @@ struct DailyStats: Codable, Equatable {
         )
     }
 
+    mutating func logCompletedCycle(date: Date = Date(), calendar: Calendar = .current) {
+        ensureCurrentDay(date, calendar: calendar)
+        completedCycles += 1
+    }
+
     init(from decoder: Decoder) throws {
         let container = try decoder.container(keyedBy: CodingKeys.self)
         dayStart = try container.decode(Date.self, forKey: .dayStart)
         totalFocusSeconds = try container.decodeIfPresent(Int.self, forKey: .totalFocusSeconds) ?? 0
         totalBreakSeconds = try container.decodeIfPresent(Int.self, forKey: .totalBreakSeconds) ?? 0
         completedSessions = try container.decodeIfPresent(Int.self, forKey: .completedSessions) ?? 0
+        completedFocusSessions = try container.decodeIfPresent(Int.self, forKey: .completedFocusSessions) ?? 0
         totalSessions = try container.decodeIfPresent(Int.self, forKey: .totalSessions) ?? completedSessions
         totalSessionSeconds = try container.decodeIfPresent(Int.self, forKey: .totalSessionSeconds)
             ?? (totalFocusSeconds + totalBreakSeconds)
         longestSessionSeconds = try container.decodeIfPresent(Int.self, forKey: .longestSessionSeconds) ?? 0
+        completedCycles = try container.decodeIfPresent(Int.self, forKey: .completedCycles) ?? 0
     }
 
     enum CodingKeys: String, CodingKey {
@@ -125,8 +149,41 @@ struct DailyStats: Codable, Equatable {
         case totalFocusSeconds
         case totalBreakSeconds
         case completedSessions
+        case completedFocusSessions
         case totalSessions
         case totalSessionSeconds
         case longestSessionSeconds
+        case completedCycles
+    }
+}
+
+/// Consecutive local days with at least one completed work session.
+struct FocusStreak: Codable, Equatable {
+    private(set) var current = 0
+    private(set) var longest = 0
+    /// Start of the most recent day counted in `current`.
+    private(set) var lastDay: Date?
+
+    mutating func logCompletedFocusSession(date: Date = Date(), calendar: Calendar = .current) {
+        let day = calendar.startOfDay(for: date)
+        // Already counted, or the clock was set back.
+        if let lastDay, lastDay >= day {
+            return
+        }
+        if let lastDay, calendar.date(byAdding: .day, value: 1, to: lastDay) == day {
+            current += 1
+        } else {
+            current = 1
+        }
+        lastDay = day
+        longest = max(longest, current)
+    }
+
+    /// Ends the current streak once a whole day has passed without a completed work session.
+    mutating func refresh(now: Date = Date(), calendar: Calendar = .current) {
+        guard let lastDay,
+              let yesterday = calendar.date(byAdding: .day, value: -1, to: calendar.startOfDay(for: now)),
+              lastDay < yesterday else { return }
+        current = 0
     }
 }
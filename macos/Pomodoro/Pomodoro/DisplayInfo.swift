@@ -0,0 +1,26 @@
+import AppKit
+
+/// A connected display, as offered for the break reminder.
+struct DisplayInfo: Identifiable, Equatable {
+    let id: CGDirectDisplayID
+    let name: String
+    /// In global screen coordinates, with the origin at the bottom left of the primary display.
+    let frame: CGRect
+    /// The display with the menu bar.
+    let isPrimary: Bool
+
+    /// Connected displays, primary first.
+    @MainActor
+    static func all() -> [DisplayInfo] {
+        NSScreen.screens.enumerated().compactMap { index, screen in
+            guard let id = screen.displayID else { return nil }
+            return DisplayInfo(id: id, name: screen.localizedName, frame: screen.frame, isPrimary: index == 0)
+        }
+    }
+}
+
+extension NSScreen {
+    var displayID: CGDirectDisplayID? {
+        deviceDescription[NSDeviceDescriptionKey("NSScreenNumber")] as? CGDirectDisplayID
+    }
+}
@@ -0,0 +1,34 @@
+import Foundation
+import IOKit.pwr_mgt
+
+/// Keeps the display from idle-sleeping while held. Only the display assertion is taken; nothing
+/// asks the system to stay awake with the lid closed.
+final class DisplaySleepAssertion {
+    private var assertionID: IOPMAssertionID?
+
+    var isHeld: Bool { assertionID != nil }
+
+    func acquire(reason: String) {
+        guard assertionID == nil else { return }
+        var id = IOPMAssertionID(0)
+        let result = IOPMAssertionCreateWithName(
+            kIOPMAssertionTypePreventUserIdleDisplaySleep as CFString,
+            IOPMAssertionLevel(kIOPMAssertionLevelOn),
+            reason as CFString,
+            &id
+        )
+        if result == kIOReturnSuccess {
+            assertionID = id
+        }
+    }
+
+    func release() {
+        guard let assertionID else { return }
+        IOPMAssertionRelease(assertionID)
+        self.assertionID = nil
+    }
+
+    deinit {
+        release()
+    }
+}
@@ -16,6 +16,10 @@ struct DurationConfig: Equatable, Hashable {
         static let countdownDuration = "durationConfig.countdownDuration"
     }
 
+    /// Upper bound for any single duration. Values loaded from defaults or typed into the
+    /// settings are clamped so a huge entry can never wrap around into a tiny timer.
+    static let maximumDurationSeconds = 24 * 60 * 60
+
     let workDuration: Int
     let shortBreakDuration: Int
     let longBreakDuration: Int
@@ -29,11 +33,96 @@ struct DurationConfig: Equatable, Hashable {
         longBreakInterval: Int,
         countdownDuration: Int = 10 * 60
     ) {
-        self.workDuration = workDuration
-        self.shortBreakDuration = shortBreakDuration
-        self.longBreakDuration = longBreakDuration
+        self.workDuration = Self.clampedDuration(workDuration)
+        self.shortBreakDuration = Self.clampedDuration(shortBreakDuration)
+        self.longBreakDuration = Self.clampedDuration(longBreakDuration)
         self.longBreakInterval = max(1, longBreakInterval)
-        self.countdownDuration = countdownDuration
+        self.countdownDuration = Self.clampedDuration(countdownDuration)
+    }
+
+    static func clampedDuration(_ seconds: Int) -> Int {
+        min(max(1, seconds), maximumDurationSeconds)
+    }
+
+    /// Converts a minute count to seconds without overflowing, clamped to `maximumDurationSeconds`.
+    static func seconds(fromMinutes minutes: Int) -> Int {
+        let (seconds, overflow) = max(0, minutes).multipliedReportingOverflow(by: 60)
+        return overflow ? maximumDurationSeconds : clampedDuration(seconds)
+    }
+
+    /// Parses "m:ss" or whole minutes, e.g. from the Set Remaining field. Any entry past
+    /// `maximumDurationSeconds`, however large, comes back as the maximum; malformed or negative
+    /// entries come back nil. Unlike the configured durations, 0 is allowed.
+    static func seconds(fromClockText text: String) -> Int? {
+        let parts = text
+            .trimmingCharacters(in: .whitespaces)
+            .split(separator: ":", omittingEmptySubsequences: false)
+        let minutes: Int
+        let secondsPart: Int
+        switch parts.count {
+        case 1:
+            guard let value = Int(parts[0]) else { return nil }
+            minutes = value
+            secondsPart = 0
+        case 2:
+            guard let value = Int(parts[0]), let seconds = Int(parts[1]), (0..<60).contains(seconds) else { return nil }
+            minutes = value
+            secondsPart = seconds
+        default:
+            return nil
+        }
+        guard minutes >= 0 else { return nil }
+        // Checked before multiplying, so no entry can overflow.
+        guard minutes < maximumDurationSeconds / 60 else { return maximumDurationSeconds }
+        return min(minutes * 60 + secondsPart, maximumDurationSeconds)
+    }
+
+    /// Plain-language summary of when breaks and long breaks happen, e.g. "Work 25 min, then a
+    /// 5 min break. Every 4 work sessions you get a 15 min long break. …". The auto-start settings
+    /// live outside this configuration, so callers pass the current values; see
+    /// `AppState.transitionRulesDescription()`.
+    func transitionRulesDescription(
+        autoStartDelayWorkSeconds: Int = 0,
+        autoStartDelayBreakSeconds: Int = 0,
+        workHours: WorkHours? = nil,
+        maxConsecutiveAutoStarts: Int = 0,
+        shortBreakGrowthSeconds: Int = 0
+    ) -> String {
+        let l10n = LocalizationManager.shared
+        let longBreakRule = longBreakInterval == 1
+            ? l10n.format("timer.rules.long_break_always", Self.minutesText(longBreakDuration))
+            : l10n.format("timer.rules.long_break_every", longBreakInterval, Self.minutesText(longBreakDuration))
+        var rules = [
+            l10n.format("timer.rules.work_then_break", Self.minutesText(workDuration), Self.minutesText(shortBreakDuration)),
+            longBreakRule
+        ]
+        // Growth only shows once a cycle has a second short break.
+        if shortBreakGrowthSeconds > 0, longBreakInterval > 2 {
+            rules.insert(l10n.format("timer.rules.short_break_growth", Self.delayText(shortBreakGrowthSeconds)), at: 1)
+        }
+        rules.append(l10n.text("timer.rules.auto_start"))
+        if autoStartDelayBreakSeconds > 0 {
+            rules.append(l10n.format("timer.rules.break_delay", Self.delayText(autoStartDelayBreakSeconds)))
+        }
+        if autoStartDelayWorkSeconds > 0 {
+            rules.append(l10n.format("timer.rules.work_delay", Self.delayText(autoStartDelayWorkSeconds)))
+        }
+        if workHours != nil {
+            rules.append(l10n.text("timer.rules.work_hours"))
+        }
+        if maxConsecutiveAutoStarts > 0 {
+            rules.append(l10n.format("timer.rules.auto_start_limit", maxConsecutiveAutoStarts))
+        }
+        return rules.joined(separator: " ")
+    }
+
+    private static func minutesText(_ seconds: Int) -> String {
+        LocalizationManager.shared.format("duration.minutes", max(1, (seconds + 30) / 60))
+    }
+
+    /// Delays are often a few seconds, so whole minutes are only used when exact.
+    private static func delayText(_ seconds: Int) -> String {
+        seconds % 60 == 0 ? minutesText(seconds) : LocalizationManager.shared.format("duration.seconds", seconds)
     }
 
     static let standard = DurationConfig(
@@ -79,3 +168,21 @@ struct DurationConfig: Equatable, Hashable {
         defaults.set(countdownDuration, forKey: DefaultsKey.countdownDuration)
     }
 }
+
+/// Timings derived from a configuration, used to preview a settings change before it is saved.
+struct CycleProjection: Equatable {
+    /// Every work session and break up to and including the long break.
+    let cycleSeconds: Int
+    let focusSecondsPerCycle: Int
+    let breakSecondsPerCycle: Int
+    /// Time until the next long break starts if the configuration were applied now. 0 during a long break.
+    let secondsUntilLongBreak: Int
+}
+
+/// Work sessions finished toward the next long break, e.g. for a row of progress dots.
+struct CycleProgress: Equatable {
+    /// Never more than `total`. Equal to `total` for the whole long break.
+    let completed: Int
+    /// The long-break interval.
+    let total: Int
+}
@@ -0,0 +1,42 @@
+import Combine
+import Foundation
+
+/// Advances the focus sound through `MusicController.focusSoundRotation` while a work session runs.
+/// Only audible work time counts toward the interval; breaks and pauses hold it.
+@MainActor
+final class FocusSoundRotationController {
+    private unowned let appState: AppState
+    private let musicController: MusicController
+    private var cancellables: Set<AnyCancellable> = []
+    private var secondsOnCurrentSound = 0
+
+    init(appState: AppState, musicController: MusicController) {
+        self.appState = appState
+        self.musicController = musicController
+
+        appState.pomodoro.$remainingSeconds
+            .dropFirst()
+            .sink { [weak self] _ in
+                self?.handleTick()
+            }
+            .store(in: &cancellables)
+
+        // Any switch, by hand or by rotation, restarts the interval.
+        musicController.$currentFocusSound
+            .removeDuplicates()
+            .combineLatest(musicController.$focusSoundRotation.removeDuplicates())
+            .sink { [weak self] _, _ in
+                self?.secondsOnCurrentSound = 0
+            }
+            .store(in: &cancellables)
+    }
+
+    private func handleTick() {
+        guard let rotation = musicController.focusSoundRotation,
+              appState.pomodoro.state == .running,
+              musicController.effectiveFocusSound != .off else { return }
+        secondsOnCurrentSound += 1
+        guard secondsOnCurrentSound >= rotation.intervalMinutes * 60 else { return }
+        musicController.startFocusSound(rotation.sound(after: musicController.currentFocusSound))
+    }
+}
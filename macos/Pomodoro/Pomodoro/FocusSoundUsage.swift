@@ -0,0 +1,30 @@
+import Combine
+import Foundation
+
+/// Adds each second of a running work session to `MusicController`'s usage total for the focus
+/// sound audible at that moment. Paused, muted-by-media and break time is not counted.
+@MainActor
+final class FocusSoundUsageTracker {
+    private unowned let appState: AppState
+    private let musicController: MusicController
+    private var cancellables: Set<AnyCancellable> = []
+
+    init(appState: AppState, musicController: MusicController) {
+        self.appState = appState
+        self.musicController = musicController
+
+        appState.pomodoro.$remainingSeconds
+            .dropFirst()
+            .sink { [weak self] _ in
+                self?.handleTick()
+            }
+            .store(in: &cancellables)
+    }
+
+    private func handleTick() {
+        guard appState.pomodoro.state == .running else { return }
+        let sound = musicController.effectiveFocusSound
+        guard sound != .off else { return }
+        musicController.recordFocusSoundUsage(sound, seconds: 1)
+    }
+}
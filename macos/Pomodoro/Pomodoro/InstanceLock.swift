@@ -0,0 +1,34 @@
+import Darwin
+import Foundation
+
+/// An advisory file lock held for the app's lifetime, so a second copy of the app can tell it isn't
+/// the first. The system releases the lock when the process exits, including after a crash.
+enum InstanceLock {
+    static let fileURL: URL = {
+        let supportDir = FileManager.default.urls(for: .applicationSupportDirectory, in: .userDomainMask).first
+            ?? URL(fileURLWithPath: NSTemporaryDirectory())
+        return supportDir
+            .appendingPathComponent("PomodoroApp", isDirectory: true)
+            .appendingPathComponent("instance.lock")
+    }()
+
+    /// Whether this process holds the lock and should own the menu bar item and media controls.
+    /// Decided on first access and never changes afterwards.
+    static let isPrimary: Bool = acquire()
+
+    private static func acquire() -> Bool {
+        try? FileManager.default.createDirectory(
+            at: fileURL.deletingLastPathComponent(),
+            withIntermediateDirectories: true
+        )
+        let descriptor = open(fileURL.path, O_RDWR | O_CREAT, 0o644)
+        // If the lock file can't be opened there's no way to tell, so act as the only copy.
+        guard descriptor >= 0 else { return true }
+        if flock(descriptor, LOCK_EX | LOCK_NB) == 0 {
+            // Left open on purpose: closing the descriptor would release the lock.
+            return true
+        }
+        close(descriptor)
+        return false
+    }
+}
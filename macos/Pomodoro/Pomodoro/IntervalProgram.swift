@@ -0,0 +1,83 @@
+import Foundation
+
+enum IntervalProgramError: LocalizedError {
+    case invalidStep(String)
+    case invalidRepeatCount
+    case programRunning
+
+    var errorDescription: String? {
+        switch self {
+        case .invalidStep(let step):
+            return LocalizationManager.shared.format("interval_program.error.invalid_step", step)
+        case .invalidRepeatCount:
+            return LocalizationManager.shared.text("interval_program.error.invalid_repeat")
+        case .programRunning:
+            return LocalizationManager.shared.text("interval_program.error.running")
+        }
+    }
+}
+
+/// A fixed routine the Pomodoro runs in place of its usual work/break rules, written as
+/// "<label> <minutes>" steps, e.g. "Work 50, Break 10, Work 25, Break 5 x2".
+struct IntervalProgram: Equatable {
+    struct Step: Equatable {
+        let label: String
+        let mode: PomodoroTimerEngine.Mode
+        let seconds: Int
+    }
+
+    let text: String
+    let steps: [Step]
+    /// Passes through `steps`, set with a trailing "x<count>". At least 1.
+    let repeatCount: Int
+
+    /// Labels containing "long" run as a long break, labels containing "break" or "rest" as a short
+    /// break, and anything else as work.
+    init(_ text: String) throws {
+        var body = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        var repeatCount = 1
+        // The repeat suffix must follow a step's minutes, so a label like "Box 25" isn't read as "x 25".
+        if let match = body.firstMatch(of: #/(\d)\s*[x×]\s*(\d+)$/#.ignoresCase()) {
+            guard let count = Int(match.output.2), count > 0 else {
+                throw IntervalProgramError.invalidRepeatCount
+            }
+            repeatCount = count
+            body = String(body[..<match.output.1.endIndex])
+        }
+        var steps: [Step] = []
+        for part in body.split(separator: ",", omittingEmptySubsequences: false) {
+            let stepText = part.trimmingCharacters(in: .whitespaces)
+            guard let match = stepText.wholeMatch(of: #/(.+?)\s+(\d+)/#),
+                  let minutes = Int(match.output.2), minutes > 0 else {
+                throw IntervalProgramError.invalidStep(stepText)
+            }
+            let label = String(match.output.1)
+            steps.append(Step(label: label, mode: Self.mode(forLabel: label), seconds: minutes * 60))
+        }
+        self.text = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        self.steps = steps
+        self.repeatCount = repeatCount
+    }
+
+    /// Every step across all repeats.
+    var totalStepCount: Int {
+        steps.count * repeatCount
+    }
+
+    /// The step at `index` counting across repeats, or nil past the end of the program.
+    func step(at index: Int) -> Step? {
+        guard index >= 0, index < totalStepCount else { return nil }
+        return steps[index % steps.count]
+    }
+
+    private static func mode(forLabel label: String) -> PomodoroTimerEngine.Mode {
+        let lowercased = label.lowercased()
+        if lowercased.contains("long") || lowercased.contains("长") {
+            return .longBreak
+        }
+        if lowercased.contains("break") || lowercased.contains("rest") || lowercased.contains("休息") {
+            return .breakTime
+        }
+        return .work
+    }
+}
@@ -37,6 +37,37 @@ final class LanguageManager: ObservableObject {
         static var simplifiedChinese: AppLanguage { .chinese }
     }
 
+    enum ClockFormat: String, CaseIterable, Identifiable {
+        /// Follows the locale and the 24-hour setting in System Settings.
+        case system
+        case twelveHour
+        case twentyFourHour
+
+        var id: String { rawValue }
+
+        var titleKey: String {
+            switch self {
+            case .system:
+                return "settings.clock_format.system"
+            case .twelveHour:
+                return "settings.clock_format.12h"
+            case .twentyFourHour:
+                return "settings.clock_format.24h"
+            }
+        }
+
+        fileprivate var dateFormatTemplate: String {
+            switch self {
+            case .system:
+                return "jmm"
+            case .twelveHour:
+                return "hmma"
+            case .twentyFourHour:
+                return "Hmm"
+            }
+        }
+    }
+
     static let shared = LanguageManager()
 
     @Published private(set) var locale: Locale
@@ -46,6 +77,11 @@ final class LanguageManager: ObservableObject {
             applyCurrentLanguage(persistSelection: true)
         }
     }
+    @Published var clockFormat: ClockFormat {
+        didSet {
+            defaults.set(clockFormat.rawValue, forKey: clockFormatDefaultsKey)
+        }
+    }
 
     private var activeDictionary: [String: String] = [:]
 
@@ -58,6 +94,7 @@ final class LanguageManager: ObservableObject {
     private let defaults = UserDefaults.standard
     private let defaultsKey = "app_language"
     private let legacyDefaultsKey = "app.localization.selectedLanguage"
+    private let clockFormatDefaultsKey = "app_clock_format"
     private var localeObserver: NSObjectProtocol?
     private var englishDictionary: [String: String] = [:]
 
@@ -68,6 +105,7 @@ final class LanguageManager: ObservableObject {
         let initialLanguage = Self.appLanguage(for: savedLanguage)
         currentLanguage = initialLanguage
         locale = Self.locale(for: initialLanguage)
+        clockFormat = ClockFormat(rawValue: defaults.string(forKey: clockFormatDefaultsKey) ?? "") ?? .system
 
         englishDictionary = loadDictionary(resourceCode: "en")
         if englishDictionary.isEmpty {
@@ -97,6 +135,19 @@ final class LanguageManager: ObservableObject {
         locale
     }
 
+    /// Prepares a time-only formatter with the app locale and the chosen 12/24-hour clock.
+    func configureTimeFormatter(_ formatter: DateFormatter) {
+        formatter.locale = locale
+        formatter.setLocalizedDateFormatFromTemplate(clockFormat.dateFormatTemplate)
+    }
+
+    /// Local wall-clock time such as "14:05" or "2:05 PM", per `clockFormat`.
+    func timeString(from date: Date) -> String {
+        let formatter = DateFormatter()
+        configureTimeFormatter(formatter)
+        return formatter.string(from: date)
+    }
+
     func setLanguage(_ identifier: String) {
         currentLanguage = Self.appLanguage(for: identifier)
     }
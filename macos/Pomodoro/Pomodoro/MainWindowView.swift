@@ -10,6 +10,7 @@ import EventKit
 import SwiftUI
 import UserNotifications
 import Charts
+import UniformTypeIdentifiers
 import FirebaseFunctions
 
 @MainActor
@@ -30,6 +31,7 @@ struct MainWindowView: View {
     @State private var longBreakMinutesText = ""
     @State private var countdownMinutesText = ""
     @State private var countdownSecondsText = ""
+    @State private var countdownRemainingText = ""
     @State private var dashboardPresetSelection: PresetSelection = .preset(Preset.shortestBuiltIn)
     @State private var dashboardWorkMinutes: Int = 25
     @State private var dashboardShortBreakMinutes: Int = 5
@@ -59,6 +61,21 @@ struct MainWindowView: View {
     @State private var systemSliderHover = false
     @State private var isCheckingPlans = false
     @State private var plansPaywallContext: SubscriptionPaywallContext?
+    @State private var completionSoundError: String?
+    @State private var notificationTemplateText = ""
+    @State private var notificationTemplateError: String?
+    @State private var intervalProgramText = ""
+    @State private var intervalProgramError: String?
+    @State private var stateBackupMessage: String?
+    @State private var dataLocations: [AppDataLocation] = []
+    @State private var displays: [DisplayInfo] = []
+    @State private var historyPruneMessage: String?
+    @State private var scheduledQuitTime = Calendar.current.date(bySettingHour: 18, minute: 0, second: 0, of: Date()) ?? Date()
+    @State private var scheduledQuitMessage: String?
+    @State private var setupCheckReport: SetupCheckReport?
+    @State private var isRunningSetupCheck = false
+    @State private var isConfirmingFactoryReset = false
+    @State private var customPlayerError: String?
     @State private var plansErrorMessage: String?
     @State private var showPlansModePicker = false
     @State private var availablePlanModes: [YourPlansMode] = []
@@ -192,7 +209,7 @@ struct MainWindowView: View {
                 Text(titleForPomodoroMode(appState.pomodoroMode))
                     .font(.system(.headline, design: .default))
                     .foregroundStyle(.secondary)
-                Text(formattedTime(appState.pomodoro.remainingSeconds))
+                Text(formattedTime(appState.displayedPomodoroSeconds))
                     .font(.system(size: 72, weight: .heavy, design: .default).monospacedDigit())
                     .scaleEffect(pomodoroStatePulse ? 1.0 : 0.98)
                     .opacity(pomodoroStatePulse ? 1.0 : 0.94)
@@ -257,7 +274,7 @@ struct MainWindowView: View {
                 LongBreakIntervalRow(
                     interval: $longBreakIntervalValue
                 ) {
-                    updateDurationConfig(longBreakInterval: longBreakIntervalValue)
+                    appState.setLongBreakInterval(longBreakIntervalValue)
                 }
             }
 
@@ -273,10 +290,10 @@ struct MainWindowView: View {
                     appState.pomodoro.resume()
                 }
                 ActionButton(languageManager.text("common.reset")) {
-                    appState.pomodoro.reset()
+                    appState.resetPomodoro()
                 }
                 ActionButton(languageManager.text("timer.skip_break"), isEnabled: actions.canSkipBreak) {
-                    appState.pomodoro.skipBreak()
+                    appState.skipBreak()
                 }
                 Button {
                     Task {
@@ -325,12 +342,13 @@ struct MainWindowView: View {
                                 Text(titleForPomodoroMode(appState.pomodoroMode))
                                     .font(.headline)
                                     .foregroundStyle(.secondary)
-                                Text(formattedTime(appState.pomodoro.remainingSeconds))
+                                Text(formattedTime(appState.displayedPomodoroSeconds))
                                     .font(.system(size: 68, weight: .heavy, design: .rounded).monospacedDigit())
                                     .contentTransition(.numericText())
                                 Text(languageManager.format("timer.state_format", labelForPomodoroState(appState.pomodoro.state)))
                                     .font(.subheadline.weight(.medium))
                                     .foregroundStyle(.secondary)
+                                cycleProgressDots(appState.pomodoro.cycleProgress)
                             }
 
                             Spacer()
@@ -372,7 +390,7 @@ struct MainWindowView: View {
                                 previewDashboardPomodoroIfIdle()
                             }
                             ActionButton(languageManager.text("timer.skip_break"), isEnabled: actions.canSkipBreak) {
-                                appState.pomodoro.skipBreak()
+                                appState.skipBreak()
                             }
                         }
 
@@ -406,7 +424,7 @@ struct MainWindowView: View {
                                 title: languageManager.text("timer.countdown"),
                                 subtitle: "Keep a simple deadline visible and adjustable without leaving the dashboard."
                             )
-                            Text(formattedTime(appState.countdown.remainingSeconds))
+                            Text(formattedTime(appState.displayedCountdownSeconds))
                                 .font(.system(size: 38, weight: .bold, design: .rounded).monospacedDigit())
                             countdownConfigurationPanel
                             countdownActionsRow
@@ -453,6 +471,7 @@ struct MainWindowView: View {
         .navigationSplitViewStyle(.balanced)
         .navigationSplitViewColumnWidth(min: 220, ideal: 250, max: 320)
         .onAppear {
+            sidebarSelection = appState.activeSidebarItem
             syncDurationTexts()
             syncLongBreakInterval()
             syncDashboardPomodoroSessionDefaults()
@@ -465,6 +484,7 @@ struct MainWindowView: View {
             syncDashboardPomodoroSessionDefaults()
         }
         .onChange(of: sidebarSelection) { oldValue, newValue in
+            appState.activeSidebarItem = newValue
             if newValue == .flow {
                 if oldValue != .flow {
                     lastNonFlowSelection = oldValue
@@ -566,7 +586,7 @@ struct MainWindowView: View {
                 Text(languageManager.text("timer.countdown"))
                     .font(.system(.headline, design: .default))
                     .foregroundStyle(.secondary)
-                Text(formattedTime(appState.countdown.remainingSeconds))
+                Text(formattedTime(appState.displayedCountdownSeconds))
                     .font(.system(size: 72, weight: .heavy, design: .default).monospacedDigit())
                     .scaleEffect(countdownStatePulse ? 1.0 : 0.98)
                     .opacity(countdownStatePulse ? 1.0 : 0.94)
@@ -578,6 +598,22 @@ struct MainWindowView: View {
                     .foregroundStyle(.secondary)
             }
 
+            if appState.countdown.state == .running || appState.countdown.state == .paused {
+                HStack {
+                    Text(languageManager.text("timer.set_remaining"))
+                        .font(.system(.body, design: .rounded))
+                    Spacer()
+                    TextField("mm:ss", text: $countdownRemainingText)
+                        .textFieldStyle(.roundedBorder)
+                        .frame(width: 72)
+                        .multilineTextAlignment(.trailing)
+                        .font(.system(.body, design: .rounded).monospacedDigit())
+                        .onSubmit {
+                            commitCountdownRemaining()
+                        }
+                }
+            }
+
             VStack(alignment: .leading, spacing: 8) {
                 Text(languageManager.text("timer.duration"))
                     .font(.system(.headline, design: .rounded))
@@ -1004,12 +1040,16 @@ struct MainWindowView: View {
             AdaptivePageGrid(minimumWidth: 360, spacing: 20) {
                 settingsGeneralModule
                 settingsAppearanceModule
+                settingsMenuBarModule
+                settingsCustomPlayerModule
             }
         case .timerFocus:
             AdaptivePageGrid(minimumWidth: 360, spacing: 20) {
                 settingsTimerPresetModule
                 settingsTimerDurationsModule
                 settingsCountdownModule
+                settingsFocusLimitsModule
+                settingsBreakReminderModule
             }
         case .notifications:
             AdaptivePageGrid(minimumWidth: 360, spacing: 20) {
@@ -1024,6 +1064,8 @@ struct MainWindowView: View {
         case .account:
             AdaptivePageGrid(minimumWidth: 360, spacing: 20) {
                 settingsAccountModule
+                settingsBackupModule
+                settingsDataFilesModule
                 settingsPoliciesModule
             }
         }
@@ -1048,6 +1090,184 @@ struct MainWindowView: View {
                     .frame(maxWidth: 320)
                 }
 
+                settingsLabeledControl(
+                    title: "Open On Launch",
+                    description: "The page the main window shows when the app starts."
+                ) {
+                    Picker("Open On Launch", selection: $appState.launchSidebarItem) {
+                        ForEach(SidebarItem.visibleItems) { item in
+                            Text(languageManager.text(item.localizationKey)).tag(item)
+                        }
+                    }
+                    .labelsHidden()
+                    .frame(maxWidth: 200)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Hide Window on Start",
+                    description: "Move the window out of the way when you start a timer. The menu bar keeps the controls."
+                ) {
+                    Toggle("Hide Window on Start", isOn: $appState.hidesWindowOnStart)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Quit When Window Closes",
+                    description: "Quit the app when you close its last window. When off, timers keep running in the menu bar."
+                ) {
+                    Toggle("Quit When Window Closes", isOn: $appState.quitsWhenLastWindowClosed)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Quit at End of Day",
+                    description: "Quit the app at this time today, with a warning a minute before. Cancel it from the menu bar."
+                ) {
+                    HStack(spacing: 8) {
+                        DatePicker("Quit Time", selection: $scheduledQuitTime, displayedComponents: .hourAndMinute)
+                            .labelsHidden()
+                            .disabled(appState.scheduledQuitDate != nil)
+                        Toggle("Quit at End of Day", isOn: scheduledQuitBinding)
+                            .toggleStyle(.switch)
+                            .labelsHidden()
+                    }
+                    if let scheduledQuitMessage {
+                        Text(scheduledQuitMessage)
+                            .font(.caption)
+                            .foregroundStyle(.secondary)
+                    }
+                }
+                .onAppear {
+                    if let scheduledQuitDate = appState.scheduledQuitDate {
+                        scheduledQuitTime = scheduledQuitDate
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Keep Display Awake",
+                    description: "Stop the display from sleeping while a work session runs."
+                ) {
+                    Toggle("Keep Display Awake", isOn: $appState.preventsDisplaySleepDuringWork)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Pause When Locked",
+                    description: "Pause a running session when you lock your Mac, and pick it back up when you unlock."
+                ) {
+                    Toggle("Pause When Locked", isOn: $appState.pausesOnScreenLock)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Timer Display",
+                    description: "Show how much of the session is left, or how long it has been running."
+                ) {
+                    Picker("Timer Display", selection: $appState.timerDisplayMode) {
+                        ForEach(TimerDisplayMode.allCases) { mode in
+                            Text(mode.title).tag(mode)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Reset Clears",
+                    description: "Go back to the first work session of the cycle, or just restart the session you're in."
+                ) {
+                    Picker("Reset Clears", selection: $appState.pomodoroResetScope) {
+                        ForEach(PomodoroResetScope.allCases) { scope in
+                            Text(scope.title).tag(scope)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Count Breaks as Sessions",
+                    description: "Include completed breaks in today's session count. Work-session goals never count breaks."
+                ) {
+                    Toggle("Count Breaks as Sessions", isOn: $appState.countsBreaksAsSessions)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                settingsLabeledControl(
+                    title: "Focus Sound Crossfade",
+                    description: "Blend between focus sounds when you switch instead of cutting over."
+                ) {
+                    Stepper(value: $musicController.focusSoundCrossfadeSeconds, in: 0...5, step: 0.5) {
+                        Text(musicController.focusSoundCrossfadeSeconds == 0
+                            ? languageManager.text("notification.off")
+                            : String(format: "%.1f s", musicController.focusSoundCrossfadeSeconds))
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Rotate Focus Sounds",
+                    description: "During work, move on to the next focus sound every few minutes. Breaks and pauses don't count."
+                ) {
+                    HStack(spacing: 12) {
+                        if let rotation = musicController.focusSoundRotation {
+                            Stepper(value: focusSoundRotationMinutesBinding, in: 1...60) {
+                                Text(languageManager.format("duration.minutes", rotation.intervalMinutes))
+                                    .font(.system(.body, design: .rounded).monospacedDigit())
+                                    .foregroundStyle(.secondary)
+                            }
+                        }
+                        Toggle("Rotate Focus Sounds", isOn: focusSoundRotationBinding)
+                            .toggleStyle(.switch)
+                            .labelsHidden()
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Mute During Countdown",
+                    description: "Pause the focus sound while a countdown runs and bring it back when the countdown ends."
+                ) {
+                    Toggle("Mute During Countdown", isOn: $musicController.countdownMutesFocusSound)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                settingsLabeledControl(title: "Time Format", description: "Used for event and schedule times.") {
+                    Picker("Time Format", selection: $languageManager.clockFormat) {
+                        ForEach(LanguageManager.ClockFormat.allCases) { format in
+                            Text(languageManager.text(format.titleKey)).tag(format)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+
                 settingsLabeledControl(
                     title: languageManager.text("settings.onboarding.title"),
                     description: languageManager.text("settings.onboarding.description")
@@ -1088,6 +1308,122 @@ struct MainWindowView: View {
         }
     }
 
+    private var settingsMenuBarModule: some View {
+        SettingsModuleCard(
+            title: "Menu Bar",
+            description: "Customize what the status item shows next to the timer."
+        ) {
+            VStack(alignment: .leading, spacing: 18) {
+                settingsLabeledControl(
+                    title: "Show in Menu Bar",
+                    description: "Turn off to remove the status item, for example while presenting. Timers keep running."
+                ) {
+                    Toggle("Show in Menu Bar", isOn: $appState.isMenuBarItemEnabled)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Idle Title",
+                    description: "Shown next to the tomato when no timer is running. Leave empty to show only the icon."
+                ) {
+                    HStack(spacing: 8) {
+                        TextField(languageManager.text("menu.status.ready"), text: menuBarIdleTitleBinding)
+                            .textFieldStyle(.roundedBorder)
+                            .frame(maxWidth: 220)
+                        Button(languageManager.text("common.reset")) {
+                            appState.menuBarIdleTitle = nil
+                        }
+                        .buttonStyle(.bordered)
+                        .disabled(appState.menuBarIdleTitle == nil)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Title Symbols",
+                    description: "The symbol before the time in each mode. Clear one to show only the time."
+                ) {
+                    HStack(spacing: 8) {
+                        menuBarGlyphField("Work", \.work)
+                        menuBarGlyphField("Break", \.shortBreak)
+                        menuBarGlyphField("Long Break", \.longBreak)
+                        menuBarGlyphField("Countdown", \.countdown)
+                        menuBarGlyphField("Idle", \.idle)
+                        Button(languageManager.text("common.reset")) {
+                            appState.menuBarGlyphs = .standard
+                        }
+                        .buttonStyle(.bordered)
+                        .disabled(appState.menuBarGlyphs == .standard)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Menu Layout",
+                    description: "Compact shows only the Pomodoro controls, Open and Quit."
+                ) {
+                    Picker("Menu Layout", selection: $appState.menuBarLayout) {
+                        ForEach(MenuBarLayout.allCases) { layout in
+                            Text(layout.title).tag(layout)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Title Follows",
+                    description: "Pick which timer the menu bar shows when the Pomodoro and a countdown run together."
+                ) {
+                    Picker("Title Follows", selection: $appState.menuBarPrimaryTimer) {
+                        ForEach(MenuBarPrimaryTimer.allCases) { timer in
+                            Text(timer.title).tag(timer)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Menu Bar Width",
+                    description: "Fixed keeps other menu bar items from shifting as the time changes."
+                ) {
+                    Picker("Menu Bar Width", selection: $appState.menuBarWidth) {
+                        ForEach(MenuBarWidth.allCases) { width in
+                            Text(width.title).tag(width)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Work/Break Indicator",
+                    description: "Add a text tag or color to the menu bar time so work and breaks are easy to tell apart."
+                ) {
+                    Picker("Work/Break Indicator", selection: $appState.menuBarModeIndicator) {
+                        ForEach(MenuBarModeIndicator.allCases) { indicator in
+                            Text(indicator.title).tag(indicator)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                    .frame(maxWidth: 320)
+                }
+            }
+        }
+    }
+
     private var settingsTimerPresetModule: some View {
         SettingsModuleCard(
             title: "Timer & Focus",
@@ -1152,80 +1488,372 @@ struct MainWindowView: View {
                 LongBreakIntervalRow(
                     interval: $longBreakIntervalValue
                 ) {
-                    updateDurationConfig(longBreakInterval: longBreakIntervalValue)
+                    appState.setLongBreakInterval(longBreakIntervalValue)
                 }
-            }
-        }
-    }
 
-    private var settingsCountdownModule: some View {
-        SettingsModuleCard(
-            title: "Countdown Default",
-            description: "Set the default countdown used across the app, with quick preset shortcuts."
-        ) {
-            countdownConfigurationPanel
-        }
-    }
+                Text(cyclePreviewText)
+                    .font(.caption)
+                    .foregroundStyle(.secondary)
+                    .help(appState.transitionRulesDescription())
 
-    private var settingsNotificationPreferencesModule: some View {
-        SettingsModuleCard(
-            title: "Notifications",
-            description: "Choose how reminders are delivered and when the app should surface them."
-        ) {
-            VStack(alignment: .leading, spacing: 14) {
-                settingsLabeledControl(title: languageManager.text("main.delivery"), description: "Decide how timer alerts should reach you.") {
-                    Picker(languageManager.text("main.delivery"), selection: $appState.notificationDeliveryStyle) {
-                        ForEach(NotificationDeliveryStyle.allCases) { style in
-                            Text(style.title).tag(style)
-                        }
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Skipped Breaks Count Toward Long Break",
+                    description: "When off, skipping a short break removes the session before it from the long-break cycle."
+                ) {
+                    Toggle("Skipped Breaks Count Toward Long Break", isOn: $appState.skipCountsTowardCycle)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Auto-Start Delays",
+                    description: "Pause at 00:00 before the next session begins on its own."
+                ) {
+                    Stepper(value: $appState.autoStartDelayBreakSeconds, in: 0...60) {
+                        Text("Before breaks: \(languageManager.format("settings.seconds_value", appState.autoStartDelayBreakSeconds))")
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                    Stepper(value: $appState.autoStartDelayWorkSeconds, in: 0...60) {
+                        Text("Before work: \(languageManager.format("settings.seconds_value", appState.autoStartDelayWorkSeconds))")
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
                     }
-                    .pickerStyle(.segmented)
                 }
 
                 Divider()
 
-                settingsLabeledControl(title: languageManager.text("settings.notifications.title"), description: "Control whether focus notifications are shown.") {
-                    Picker(languageManager.text("settings.notifications.title"), selection: $appState.notificationPreference) {
-                        ForEach(NotificationPreference.allCases) { preference in
-                            Text(preference.title).tag(preference)
-                        }
+                settingsLabeledControl(
+                    title: "Minimum Break",
+                    description: "Keep Skip Break unavailable until the break has run this long."
+                ) {
+                    Stepper(value: $appState.minimumBreakSeconds, in: 0...(15 * 60), step: 30) {
+                        Text(appState.minimumBreakSeconds == 0
+                            ? languageManager.text("notification.off")
+                            : formattedDuration(appState.minimumBreakSeconds))
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
                     }
-                    .pickerStyle(.segmented)
                 }
 
                 Divider()
 
-                settingsLabeledControl(title: languageManager.text("main.reminder"), description: "Choose how reminder follow-ups should behave.") {
-                    Picker(languageManager.text("main.reminder"), selection: $appState.reminderPreference) {
-                        ForEach(ReminderPreference.allCases) { preference in
-                            Text(preference.title).tag(preference)
+                settingsLabeledControl(
+                    title: "Growing Short Breaks",
+                    description: "Add this much to each short break after the first in a cycle, up to the long break's length."
+                ) {
+                    Stepper(value: $appState.shortBreakGrowthSeconds, in: 0...(5 * 60), step: 30) {
+                        Text(appState.shortBreakGrowthSeconds == 0
+                            ? languageManager.text("notification.off")
+                            : "+\(formattedDuration(appState.shortBreakGrowthSeconds))")
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                    if appState.shortBreakGrowthSeconds > 0 {
+                        Text("Next short break: \(formattedDuration(appState.pomodoro.currentShortBreakSeconds))")
+                            .font(.caption)
+                            .foregroundStyle(.secondary)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Interval Program",
+                    description: "A custom routine to run instead of the usual cycle, started from the menu bar. Write steps as a label and minutes; labels with \"break\" or \"long\" run as breaks. End with x2 to repeat."
+                ) {
+                    HStack(spacing: 8) {
+                        TextField("Work 50, Break 10, Work 25, Break 5 x2", text: $intervalProgramText)
+                            .textFieldStyle(.roundedBorder)
+                            .onSubmit(saveIntervalProgram)
+                        Button("Save") {
+                            saveIntervalProgram()
                         }
+                        .buttonStyle(.bordered)
                     }
-                    .pickerStyle(.segmented)
+                    if let intervalProgramError {
+                        Text(intervalProgramError)
+                            .font(.caption)
+                            .foregroundStyle(.red)
+                    }
+                }
+                .onAppear {
+                    intervalProgramText = appState.pomodoro.program?.text ?? ""
                 }
             }
         }
     }
 
-    private var settingsPermissionsModule: some View {
+    private var settingsBreakReminderModule: some View {
         SettingsModuleCard(
-            title: "Permissions & Sync",
-            description: "Review system access for notifications, calendar, and reminders."
+            title: "Break Reminder",
+            description: "A gentle full-screen reminder to look away from the screen during breaks."
         ) {
             VStack(alignment: .leading, spacing: 14) {
-                settingsPermissionRow(
-                    title: "Notifications",
-                    status: notificationStatusText(notificationStatus),
-                    statusColor: notificationStatusColor(notificationStatus),
-                    buttonTitle: notificationStatus == .authorized ? "Open Settings" : "Enable",
-                    action: handleNotificationAccessRequest
-                )
+                settingsLabeledControl(
+                    title: "Full-Screen Reminder",
+                    description: "Cover the screen when a break starts. Dismiss it with Escape or the button."
+                ) {
+                    Toggle("Full-Screen Reminder", isOn: $appState.showsBreakOverlay)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                }
 
                 Divider()
 
-                settingsPermissionRow(
-                    title: "Calendar",
-                    status: eventStatusText(calendarStatus),
+                settingsLabeledControl(
+                    title: "Display",
+                    description: "If the chosen display is disconnected, the main display is used."
+                ) {
+                    Picker("Display", selection: $appState.breakOverlayDisplayID) {
+                        Text("Main Display").tag(CGDirectDisplayID?.none)
+                        ForEach(displays) { display in
+                            Text(display.isPrimary ? "\(display.name) (Main)" : display.name)
+                                .tag(CGDirectDisplayID?.some(display.id))
+                        }
+                    }
+                    .frame(maxWidth: 260)
+                    .disabled(!appState.showsBreakOverlay)
+                }
+            }
+        }
+        .onAppear {
+            displays = DisplayInfo.all()
+        }
+        .onReceive(NotificationCenter.default.publisher(for: NSApplication.didChangeScreenParametersNotification)) { _ in
+            displays = DisplayInfo.all()
+        }
+    }
+
+    private var settingsFocusLimitsModule: some View {
+        SettingsModuleCard(
+            title: "Focus Limits",
+            description: "Guard against burnout by limiting how long the Pomodoro keeps going on its own."
+        ) {
+            VStack(alignment: .leading, spacing: 14) {
+                settingsLabeledControl(
+                    title: "Daily Focus Cap",
+                    description: "After this much focus today, breaks stop rolling into new work sessions. Resets at midnight."
+                ) {
+                    Stepper(value: dailyFocusCapMinutesBinding, in: 0...(16 * 60), step: 30) {
+                        Text(dailyFocusCapLabel)
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Daily Session Goal",
+                    description: "Completing this many focus sessions in a day sends a celebration instead of the usual notification."
+                ) {
+                    Stepper(value: $appState.dailySessionGoal, in: 0...24) {
+                        Text(appState.dailySessionGoal == 0 ? languageManager.text("notification.off") : "\(appState.dailySessionGoal)")
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                    Toggle("Stop auto-starting work after the goal", isOn: $appState.stopsAutoStartAtDailyGoal)
+                        .disabled(appState.dailySessionGoal == 0)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Unattended Session Limit",
+                    description: "Stop after this many work sessions start on their own in a row. Starting or pausing by hand resets the count."
+                ) {
+                    Stepper(value: $appState.maxConsecutiveAutoStarts, in: 0...24) {
+                        Text(appState.maxConsecutiveAutoStarts == 0 ? languageManager.text("notification.off") : "\(appState.maxConsecutiveAutoStarts)")
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Auto-Start Hours",
+                    description: "Only let breaks roll into new work sessions during these hours. You can still start a session by hand at any time."
+                ) {
+                    Toggle("Auto-Start Hours", isOn: workHoursEnabledBinding)
+                        .toggleStyle(.switch)
+                        .labelsHidden()
+                    if let workHours = appState.workHours {
+                        HStack(spacing: 4) {
+                            ForEach(1...7, id: \.self) { weekday in
+                                Toggle(
+                                    Calendar.current.veryShortWeekdaySymbols[weekday - 1],
+                                    isOn: workHoursWeekdayBinding(weekday)
+                                )
+                                .toggleStyle(.button)
+                                .disabled(workHours.windows.count == 1 && workHours.windows[weekday] != nil)
+                            }
+                        }
+                        HStack(spacing: 8) {
+                            DatePicker("From", selection: workHoursTimeBinding(isStart: true), displayedComponents: .hourAndMinute)
+                            DatePicker("To", selection: workHoursTimeBinding(isStart: false), displayedComponents: .hourAndMinute)
+                        }
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Weekly Focus Goal",
+                    description: "Track focus time across the week in the daily summary."
+                ) {
+                    Stepper(value: weeklyFocusGoalHoursBinding, in: 0...80) {
+                        Text(appState.weeklyFocusGoalSeconds == 0 ? languageManager.text("notification.off") : "\(appState.weeklyFocusGoalSeconds / 3600)h")
+                            .font(.system(.body, design: .rounded).monospacedDigit())
+                            .foregroundStyle(.secondary)
+                    }
+                    Picker("Week Starts On", selection: $appState.weekStartDay) {
+                        ForEach(Array(Calendar.current.weekdaySymbols.enumerated()), id: \.offset) { index, name in
+                            Text(name).tag(index + 1)
+                        }
+                    }
+                    .disabled(appState.weeklyFocusGoalSeconds == 0)
+                }
+            }
+        }
+    }
+
+    private var settingsCountdownModule: some View {
+        SettingsModuleCard(
+            title: "Countdown Default",
+            description: "Set the default countdown used across the app, with quick preset shortcuts."
+        ) {
+            countdownConfigurationPanel
+        }
+    }
+
+    private var settingsNotificationPreferencesModule: some View {
+        SettingsModuleCard(
+            title: "Notifications",
+            description: "Choose how reminders are delivered and when the app should surface them."
+        ) {
+            VStack(alignment: .leading, spacing: 14) {
+                settingsLabeledControl(title: languageManager.text("main.delivery"), description: "Decide how timer alerts should reach you.") {
+                    Picker(languageManager.text("main.delivery"), selection: $appState.notificationDeliveryStyle) {
+                        ForEach(NotificationDeliveryStyle.allCases) { style in
+                            Text(style.title).tag(style)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                }
+
+                Divider()
+
+                settingsLabeledControl(title: languageManager.text("settings.notifications.title"), description: "Control whether focus notifications are shown.") {
+                    Picker(languageManager.text("settings.notifications.title"), selection: $appState.notificationPreference) {
+                        ForEach(NotificationPreference.allCases) { preference in
+                            Text(preference.title).tag(preference)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                }
+
+                Divider()
+
+                settingsLabeledControl(title: languageManager.text("main.reminder"), description: "Choose how reminder follow-ups should behave.") {
+                    Picker(languageManager.text("main.reminder"), selection: $appState.reminderPreference) {
+                        ForEach(ReminderPreference.allCases) { preference in
+                            Text(preference.title).tag(preference)
+                        }
+                    }
+                    .pickerStyle(.segmented)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Notification Group",
+                    description: "Each new system alert replaces the last one with this group name. Leave empty to keep every alert."
+                ) {
+                    TextField("Off", text: notificationGroupBinding)
+                        .textFieldStyle(.roundedBorder)
+                        .frame(maxWidth: 220)
+                }
+
+                Divider()
+
+                settingsLabeledControl(title: "Completion Sound", description: "Play your own audio file when a session or countdown finishes.") {
+                    HStack(spacing: 8) {
+                        Text(completionSoundLabel)
+                            .font(.caption)
+                            .foregroundStyle(.secondary)
+                            .lineLimit(1)
+                            .truncationMode(.middle)
+                        Spacer()
+                        Button("Choose…") {
+                            chooseCompletionSound()
+                        }
+                        .buttonStyle(.bordered)
+                        Button(languageManager.text("common.reset")) {
+                            try? appState.setCompletionSoundFile(path: "")
+                        }
+                        .buttonStyle(.bordered)
+                        .disabled(appState.completionSoundPath == nil)
+                    }
+                    if let completionSoundError {
+                        Text(completionSoundError)
+                            .font(.caption)
+                            .foregroundStyle(.red)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Message Template",
+                    description: "Custom text for session completion alerts. Use {mode}, {count} and {next_minutes}; leave empty for the default."
+                ) {
+                    HStack(spacing: 8) {
+                        TextField("Nice work, {count} down!", text: $notificationTemplateText)
+                            .textFieldStyle(.roundedBorder)
+                            .onSubmit(saveNotificationTemplate)
+                        Button("Save") {
+                            saveNotificationTemplate()
+                        }
+                        .buttonStyle(.bordered)
+                    }
+                    if let notificationTemplateError {
+                        Text(notificationTemplateError)
+                            .font(.caption)
+                            .foregroundStyle(.red)
+                    }
+                }
+                .onAppear {
+                    notificationTemplateText = appState.notificationBodyTemplate?.text ?? ""
+                }
+            }
+        }
+    }
+
+    private var settingsPermissionsModule: some View {
+        SettingsModuleCard(
+            title: "Permissions & Sync",
+            description: "Review system access for notifications, calendar, and reminders."
+        ) {
+            VStack(alignment: .leading, spacing: 14) {
+                settingsPermissionRow(
+                    title: "Notifications",
+                    status: notificationStatusText(notificationStatus),
+                    statusColor: notificationStatusColor(notificationStatus),
+                    buttonTitle: notificationStatus == .authorized ? "Open Settings" : "Enable",
+                    action: handleNotificationAccessRequest
+                )
+
+                Divider()
+
+                settingsPermissionRow(
+                    title: "Calendar",
+                    status: eventStatusText(calendarStatus),
                     statusColor: eventStatusColor(calendarStatus),
                     buttonTitle: eventStatusColor(calendarStatus) == .green ? "Open Settings" : "Enable",
                     action: handleCalendarAccessRequest
@@ -1240,6 +1868,20 @@ struct MainWindowView: View {
                     buttonTitle: eventStatusColor(remindersStatus) == .green ? "Open Settings" : "Enable",
                     action: handleRemindersAccessRequest
                 )
+
+                Divider()
+
+                setupCheckSection
+
+                Divider()
+
+                settingsPermissionRow(
+                    title: "Automation (Media Control)",
+                    status: automationStatusText(permissionsManager.automationStatus),
+                    statusColor: automationStatusColor(permissionsManager.automationStatus),
+                    buttonTitle: permissionsManager.automationStatus == .denied ? "Open Settings" : "Check",
+                    action: handleAutomationAccessRequest
+                )
             }
         }
     }
@@ -1268,93 +1910,383 @@ struct MainWindowView: View {
                         .fixedSize(horizontal: false, vertical: true)
                 }
 
-                Button {
-                    Task {
-                        await subscriptionStore.restorePurchases()
-                    }
-                } label: {
-                    if subscriptionStore.isRestoring {
-                        ProgressView()
-                            .controlSize(.small)
-                    } else {
-                        Text("Restore & Sync Subscription")
+                Button {
+                    Task {
+                        await subscriptionStore.restorePurchases()
+                    }
+                } label: {
+                    if subscriptionStore.isRestoring {
+                        ProgressView()
+                            .controlSize(.small)
+                    } else {
+                        Text("Restore & Sync Subscription")
+                    }
+                }
+                .buttonStyle(.bordered)
+                .disabled(subscriptionStore.isRestoring)
+
+                Button("Manage Subscription") {
+                    showSettingsPlansSheet = true
+                }
+                .buttonStyle(.borderedProminent)
+
+                if let errorMessage = subscriptionStore.errorMessage, !errorMessage.isEmpty {
+                    Text(errorMessage)
+                        .font(.caption)
+                        .foregroundStyle(.red)
+                }
+
+                if let productLoadErrorMessage = subscriptionStore.productLoadErrorMessage,
+                   !productLoadErrorMessage.isEmpty {
+                    Text(productLoadErrorMessage)
+                        .font(.caption)
+                        .foregroundStyle(.red)
+                }
+            }
+        }
+    }
+
+    private var settingsAIFeaturesModule: some View {
+        SettingsModuleCard(
+            title: "AI Access & Features",
+            description: "See what your tier unlocks, then open the subscription sheet when you need full plan details."
+        ) {
+            VStack(alignment: .leading, spacing: 16) {
+                if !featureGate.aiUsageProgressItems.isEmpty {
+                    VStack(alignment: .leading, spacing: 12) {
+                        ForEach(featureGate.aiUsageProgressItems, id: \.title) { item in
+                            VStack(alignment: .leading, spacing: 6) {
+                                HStack {
+                                    Text(item.title)
+                                        .font(.subheadline.weight(.medium))
+                                    Spacer()
+                                    Text("\(item.usedPercentage)% used")
+                                        .font(.caption)
+                                        .foregroundStyle(.secondary)
+                                }
+                                ProgressView(value: item.usedRatio)
+                                    .tint(usageColor(for: item.usedRatio))
+                            }
+                        }
+                    }
+                }
+
+                HStack(alignment: .center, spacing: 12) {
+                    VStack(alignment: .leading, spacing: 4) {
+                        Text("Plan Comparison")
+                            .font(.subheadline.weight(.semibold))
+                        Text("Open the subscription pop-up to compare Free, Plus, and Pro in detail.")
+                            .font(.caption)
+                            .foregroundStyle(.secondary)
+                    }
+                    Spacer()
+                    Button("Compare Plans") {
+                        showSettingsPlansSheet = true
+                    }
+                    .buttonStyle(.bordered)
+                }
+            }
+        }
+    }
+
+    private var settingsAccountModule: some View {
+        SettingsModuleCard(
+            title: "Account",
+            description: "Manage sign-in state and cloud-connected access from one place."
+        ) {
+            CloudSettingsSection()
+                .environmentObject(authViewModel)
+                .environmentObject(LocalizationManager.shared)
+        }
+    }
+
+    private var settingsCustomPlayerModule: some View {
+        SettingsModuleCard(
+            title: "Custom Media Player",
+            description: "Control a player without built-in support using AppleScript. Import a JSON file with name, playPauseScript, nextScript, previousScript and metadataScript."
+        ) {
+            VStack(alignment: .leading, spacing: 10) {
+                HStack(spacing: 8) {
+                    Text(appState.nowPlayingRouter.customPlayer?.name ?? "None")
+                        .font(.caption)
+                        .foregroundStyle(.secondary)
+                    Spacer()
+                    Button("Import…") {
+                        importCustomPlayer()
+                    }
+                    .buttonStyle(.bordered)
+                    Button("Remove") {
+                        appState.nowPlayingRouter.removeCustomPlayer()
+                        customPlayerError = nil
+                    }
+                    .buttonStyle(.bordered)
+                    .disabled(appState.nowPlayingRouter.customPlayer == nil)
+                }
+                if let customPlayerError {
+                    Text(customPlayerError)
+                        .font(.caption)
+                        .foregroundStyle(.red)
+                }
+            }
+        }
+    }
+
+    private var settingsBackupModule: some View {
+        SettingsModuleCard(
+            title: "Backup",
+            description: "Copy your settings and session history as text to move them to another Mac, or export just the settings as a JSON file to share."
+        ) {
+            VStack(alignment: .leading, spacing: 10) {
+                HStack(spacing: 8) {
+                    Button("Copy Backup") {
+                        copyStateBackup()
+                    }
+                    .buttonStyle(.bordered)
+                    Button("Restore from Clipboard") {
+                        restoreStateBackup()
+                    }
+                    .buttonStyle(.bordered)
+                }
+                HStack(spacing: 8) {
+                    Button("Export Settings…") {
+                        exportSettingsFile()
+                    }
+                    .buttonStyle(.bordered)
+                    Button("Import Settings…") {
+                        importSettingsFile()
+                    }
+                    .buttonStyle(.bordered)
+                }
+                Button("Reset to Defaults…", role: .destructive) {
+                    isConfirmingFactoryReset = true
+                }
+                .buttonStyle(.bordered)
+                .confirmationDialog(
+                    "Reset everything to factory defaults?",
+                    isPresented: $isConfirmingFactoryReset
+                ) {
+                    Button("Reset", role: .destructive) {
+                        resetToFactoryDefaults()
+                    }
+                } message: {
+                    Text("This stops both timers and clears all settings, today's stats and your session history. It can't be undone.")
+                }
+                if let stateBackupMessage {
+                    Text(stateBackupMessage)
+                        .font(.caption)
+                        .foregroundStyle(.secondary)
+                }
+            }
+        }
+    }
+
+    private var settingsDataFilesModule: some View {
+        SettingsModuleCard(
+            title: "Data Files",
+            description: "Where your settings and history are stored on this Mac."
+        ) {
+            VStack(alignment: .leading, spacing: 12) {
+                ForEach(dataLocations) { location in
+                    HStack(alignment: .firstTextBaseline, spacing: 12) {
+                        VStack(alignment: .leading, spacing: 2) {
+                            Text(dataLocationTitle(location.kind))
+                            Text(location.url.path)
+                                .font(.caption)
+                                .foregroundStyle(.secondary)
+                                .textSelection(.enabled)
+                                .lineLimit(2)
+                                .truncationMode(.middle)
+                        }
+                        Spacer(minLength: 8)
+                        Text(location.sizeBytes.map {
+                            ByteCountFormatter.string(fromByteCount: $0, countStyle: .file)
+                        } ?? "Not created")
+                            .font(.caption.monospacedDigit())
+                            .foregroundStyle(.secondary)
+                        Button("Show in Finder") {
+                            NSWorkspace.shared.activateFileViewerSelecting([location.url])
+                        }
+                        .buttonStyle(.bordered)
+                        .disabled(location.sizeBytes == nil)
+                    }
+                }
+                Button("Refresh") {
+                    dataLocations = AppDataLocation.all()
+                }
+                .buttonStyle(.bordered)
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Keep History",
+                    description: "Sessions older than this are deleted each time the app launches."
+                ) {
+                    Picker("Keep History", selection: $appState.historyRetentionDays) {
+                        Text("Forever").tag(0)
+                        ForEach([30, 90, 180, 365], id: \.self) { days in
+                            Text("\(days) days").tag(days)
+                        }
+                    }
+                    .labelsHidden()
+                    .frame(maxWidth: 160)
+                    Button("Delete Older Sessions Now") {
+                        let removed = appState.pruneHistory(olderThanDays: appState.historyRetentionDays)
+                        historyPruneMessage = removed == 1 ? "Removed 1 session." : "Removed \(removed) sessions."
+                        dataLocations = AppDataLocation.all()
+                    }
+                    .buttonStyle(.bordered)
+                    .disabled(appState.historyRetentionDays == 0)
+                    if let historyPruneMessage {
+                        Text(historyPruneMessage)
+                            .font(.caption)
+                            .foregroundStyle(.secondary)
+                    }
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Focus Streak",
+                    description: "Start counting days in a row from zero, including the longest streak."
+                ) {
+                    Button("Reset Streak") {
+                        appState.resetFocusStreak()
+                    }
+                    .buttonStyle(.bordered)
+                    .disabled(appState.focusStreak.longest == 0)
+                }
+
+                Divider()
+
+                settingsLabeledControl(
+                    title: "Timer State File",
+                    description: "Keep a JSON file updated with both timers' state, for scripts that watch it."
+                ) {
+                    HStack(spacing: 8) {
+                        Text(appState.stateMirrorPath ?? "Off")
+                            .font(.caption)
+                            .foregroundStyle(.secondary)
+                            .lineLimit(1)
+                            .truncationMode(.middle)
+                        Spacer()
+                        Button("Choose…") {
+                            chooseStateMirrorFile()
+                        }
+                        .buttonStyle(.bordered)
+                        Button("Stop") {
+                            appState.stateMirrorPath = nil
+                        }
+                        .buttonStyle(.bordered)
+                        .disabled(appState.stateMirrorPath == nil)
+                    }
+                }
+            }
+        }
+        .onAppear {
+            dataLocations = AppDataLocation.all()
+        }
+    }
+
+    private func menuBarGlyphField(_ title: String, _ keyPath: WritableKeyPath<MenuBarGlyphs, String>) -> some View {
+        VStack(spacing: 2) {
+            TextField(title, text: Binding(
+                get: { appState.menuBarGlyphs[keyPath: keyPath] },
+                set: { appState.menuBarGlyphs[keyPath: keyPath] = $0 }
+            ))
+            .textFieldStyle(.roundedBorder)
+            .multilineTextAlignment(.center)
+            .frame(width: 44)
+            Text(title)
+                .font(.caption2)
+                .foregroundStyle(.secondary)
+        }
+    }
+
+    private var setupCheckSection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            HStack(spacing: 8) {
+                Button("Check Setup") {
+                    isRunningSetupCheck = true
+                    Task { @MainActor in
+                        setupCheckReport = await SetupCheck.run(nowPlayingRouter: appState.nowPlayingRouter)
+                        isRunningSetupCheck = false
                     }
                 }
                 .buttonStyle(.bordered)
-                .disabled(subscriptionStore.isRestoring)
-
-                Button("Manage Subscription") {
-                    showSettingsPlansSheet = true
-                }
-                .buttonStyle(.borderedProminent)
-
-                if let errorMessage = subscriptionStore.errorMessage, !errorMessage.isEmpty {
-                    Text(errorMessage)
-                        .font(.caption)
-                        .foregroundStyle(.red)
+                .disabled(isRunningSetupCheck)
+                if isRunningSetupCheck {
+                    ProgressView()
+                        .controlSize(.small)
                 }
+            }
+            if let setupCheckReport {
+                setupCheckRow("Notifications", setupCheckReport.notifications)
+                setupCheckRow("Media", setupCheckReport.media)
+                setupCheckRow("Sync Service", setupCheckReport.backend)
+                setupCheckRow("Storage", setupCheckReport.storage)
+            }
+        }
+    }
 
-                if let productLoadErrorMessage = subscriptionStore.productLoadErrorMessage,
-                   !productLoadErrorMessage.isEmpty {
-                    Text(productLoadErrorMessage)
-                        .font(.caption)
-                        .foregroundStyle(.red)
-                }
+    private func setupCheckRow(_ title: String, _ check: SetupCheckReport.Check) -> some View {
+        HStack(alignment: .firstTextBaseline, spacing: 8) {
+            Image(systemName: check.passed ? "checkmark.circle.fill" : "exclamationmark.triangle.fill")
+                .foregroundStyle(check.passed ? .green : .orange)
+            VStack(alignment: .leading, spacing: 2) {
+                Text(title)
+                    .font(.subheadline.weight(.semibold))
+                Text(check.detail)
+                    .font(.caption)
+                    .foregroundStyle(.secondary)
+                    .textSelection(.enabled)
             }
         }
     }
 
-    private var settingsAIFeaturesModule: some View {
-        SettingsModuleCard(
-            title: "AI Access & Features",
-            description: "See what your tier unlocks, then open the subscription sheet when you need full plan details."
-        ) {
-            VStack(alignment: .leading, spacing: 16) {
-                if !featureGate.aiUsageProgressItems.isEmpty {
-                    VStack(alignment: .leading, spacing: 12) {
-                        ForEach(featureGate.aiUsageProgressItems, id: \.title) { item in
-                            VStack(alignment: .leading, spacing: 6) {
-                                HStack {
-                                    Text(item.title)
-                                        .font(.subheadline.weight(.medium))
-                                    Spacer()
-                                    Text("\(item.usedPercentage)% used")
-                                        .font(.caption)
-                                        .foregroundStyle(.secondary)
-                                }
-                                ProgressView(value: item.usedRatio)
-                                    .tint(usageColor(for: item.usedRatio))
-                            }
-                        }
-                    }
+    private var scheduledQuitBinding: Binding<Bool> {
+        Binding(
+            get: { appState.scheduledQuitDate != nil },
+            set: { isOn in
+                scheduledQuitMessage = nil
+                guard isOn else {
+                    appState.clearScheduledQuit()
+                    return
                 }
-
-                HStack(alignment: .center, spacing: 12) {
-                    VStack(alignment: .leading, spacing: 4) {
-                        Text("Plan Comparison")
-                            .font(.subheadline.weight(.semibold))
-                        Text("Open the subscription pop-up to compare Free, Plus, and Pro in detail.")
-                            .font(.caption)
-                            .foregroundStyle(.secondary)
-                    }
-                    Spacer()
-                    Button("Compare Plans") {
-                        showSettingsPlansSheet = true
-                    }
-                    .buttonStyle(.bordered)
+                // The picker only sets a time of day; always aim for today.
+                let components = Calendar.current.dateComponents([.hour, .minute], from: scheduledQuitTime)
+                let today = Calendar.current.date(
+                    bySettingHour: components.hour ?? 0,
+                    minute: components.minute ?? 0,
+                    second: 0,
+                    of: Date()
+                )
+                if today.map({ appState.scheduleQuit(at: $0) }) != true {
+                    scheduledQuitMessage = "That time has already passed today."
                 }
             }
+        )
+    }
+
+    private func cycleProgressDots(_ progress: CycleProgress) -> some View {
+        HStack(spacing: 6) {
+            ForEach(0..<progress.total, id: \.self) { index in
+                Circle()
+                    .fill(index < progress.completed ? Color.accentColor : Color.primary.opacity(0.15))
+                    .frame(width: 8, height: 8)
+            }
         }
+        .accessibilityElement(children: .ignore)
+        .accessibilityLabel("\(progress.completed) of \(progress.total) sessions until long break")
     }
 
-    private var settingsAccountModule: some View {
-        SettingsModuleCard(
-            title: "Account",
-            description: "Manage sign-in state and cloud-connected access from one place."
-        ) {
-            CloudSettingsSection()
-                .environmentObject(authViewModel)
-                .environmentObject(LocalizationManager.shared)
+    private func dataLocationTitle(_ kind: AppDataLocation.Kind) -> String {
+        switch kind {
+        case .settings:
+            return "Settings & Today's Stats"
+        case .sessionHistory:
+            return "Session History"
+        case .analyticsCache:
+            return "Analytics Cache"
         }
     }
 
@@ -1539,38 +2471,46 @@ struct MainWindowView: View {
 
                     Spacer()
 
-                    HStack(spacing: 10) {
-                        Button {
-                            appState.nowPlayingRouter.previousTrack()
-                        } label: {
-                            Image(systemName: "backward.fill")
-                                .font(.system(size: 16, weight: .semibold))
-                                .frame(width: 28, height: 28)
-                        }
-                        .buttonStyle(.borderless)
-                        .disabled(!appState.nowPlayingRouter.isAvailable)
+                    if MediaBackendInfo(automationStatus: permissionsManager.automationStatus).canControl {
+                        HStack(spacing: 10) {
+                            Button {
+                                appState.nowPlayingRouter.previousTrack()
+                            } label: {
+                                Image(systemName: "backward.fill")
+                                    .font(.system(size: 16, weight: .semibold))
+                                    .frame(width: 28, height: 28)
+                            }
+                            .buttonStyle(.borderless)
+                            .disabled(!appState.nowPlayingRouter.isAvailable)
 
-                        Button {
-                            appState.nowPlayingRouter.playPause()
-                        } label: {
-                            Image(systemName: appState.nowPlayingRouter.isPlaying ? "pause.fill" : "play.fill")
-                                .font(.system(size: 18, weight: .semibold))
-                                .frame(width: 34, height: 34)
-                                .foregroundStyle(.white)
-                                .background(Circle().fill(Color.accentColor))
-                        }
-                        .buttonStyle(.plain)
-                        .disabled(!appState.nowPlayingRouter.isAvailable)
+                            Button {
+                                appState.nowPlayingRouter.playPause()
+                            } label: {
+                                Image(systemName: appState.nowPlayingRouter.isPlaying ? "pause.fill" : "play.fill")
+                                    .font(.system(size: 18, weight: .semibold))
+                                    .frame(width: 34, height: 34)
+                                    .foregroundStyle(.white)
+                                    .background(Circle().fill(Color.accentColor))
+                            }
+                            .buttonStyle(.plain)
+                            .disabled(!appState.nowPlayingRouter.isAvailable)
 
-                        Button {
-                            appState.nowPlayingRouter.nextTrack()
-                        } label: {
-                            Image(systemName: "forward.fill")
-                                .font(.system(size: 16, weight: .semibold))
-                                .frame(width: 28, height: 28)
+                            Button {
+                                appState.nowPlayingRouter.nextTrack()
+                            } label: {
+                                Image(systemName: "forward.fill")
+                                    .font(.system(size: 16, weight: .semibold))
+                                    .frame(width: 28, height: 28)
+                            }
+                            .buttonStyle(.borderless)
+                            .disabled(!appState.nowPlayingRouter.isAvailable)
                         }
-                        .buttonStyle(.borderless)
-                        .disabled(!appState.nowPlayingRouter.isAvailable)
+                    } else if let message = appState.nowPlayingRouter.controlStatus(
+                        automationStatus: permissionsManager.automationStatus
+                    ).message {
+                        Text(message)
+                            .font(.system(.caption, design: .rounded))
+                            .foregroundStyle(.secondary)
                     }
                 }
             } else {
@@ -1724,7 +2664,10 @@ struct MainWindowView: View {
         .frame(maxWidth: .infinity, alignment: .leading)
         .fixedSize(horizontal: false, vertical: true)
         .onAppear {
-            appState.nowPlayingRouter.startPollingIfNeeded()
+            // A second copy of the app leaves the player to the first.
+            if InstanceLock.isPrimary {
+                appState.nowPlayingRouter.startPollingIfNeeded()
+            }
         }
     }
 
@@ -1972,6 +2915,37 @@ struct MainWindowView: View {
         }
     }
 
+    private func automationStatusText(_ status: AutomationPermissionStatus) -> String {
+        switch status {
+        case .granted:
+            return languageManager.text("permission.authorized")
+        case .denied:
+            return languageManager.text("permission.denied")
+        case .unknown:
+            return languageManager.text("permission.unknown")
+        }
+    }
+
+    private func automationStatusColor(_ status: AutomationPermissionStatus) -> Color {
+        switch status {
+        case .granted:
+            return .green
+        case .denied:
+            return .red
+        case .unknown:
+            return .secondary
+        }
+    }
+
+    private func handleAutomationAccessRequest() {
+        if permissionsManager.automationStatus == .denied {
+            permissionsManager.openAutomationSettings()
+        }
+        Task {
+            await permissionsManager.refreshAutomationStatus()
+        }
+    }
+
     private func notificationStatusText(_ status: UNAuthorizationStatus) -> String {
         switch status {
         case .notDetermined:
@@ -2042,7 +3016,7 @@ struct MainWindowView: View {
         case countdownSeconds
     }
 
-    private enum SidebarItem: String, CaseIterable, Identifiable {
+    enum SidebarItem: String, CaseIterable, Identifiable {
         case dashboard
         case workspace
         case tasks
@@ -2398,12 +3372,67 @@ struct MainWindowView: View {
                 VStack(alignment: .leading, spacing: 6) {
                     SummaryRow(title: languageManager.text("summary.focus_time"), value: formattedDuration(stats.totalFocusSeconds))
                     SummaryRow(title: languageManager.text("summary.break_time"), value: formattedDuration(stats.totalBreakSeconds))
-                    SummaryRow(title: languageManager.text("summary.sessions"), value: "\(stats.completedSessions)")
+                    SummaryRow(
+                        title: languageManager.text("summary.sessions"),
+                        value: "\(stats.completedSessionCount(includingBreaks: appState.countsBreaksAsSessions))"
+                    )
+                    SummaryRow(title: languageManager.text("summary.cycles"), value: "\(stats.completedCycles)")
+                    if appState.focusStreak.longest > 0 {
+                        SummaryRow(
+                            title: languageManager.text("summary.streak"),
+                            value: languageManager.format(
+                                "summary.streak_value",
+                                appState.focusStreak.current,
+                                appState.focusStreak.longest
+                            )
+                        )
+                    }
+                    SummaryRow(title: languageManager.text("summary.since_launch"), value: sinceLaunchText)
+                    let averageFocusSeconds = SessionRecordStore.shared.averageCompletedFocusSeconds()
+                    if averageFocusSeconds > 0 {
+                        SummaryRow(
+                            title: languageManager.text("summary.average_session"),
+                            value: formattedDuration(averageFocusSeconds)
+                        )
+                    }
+                    if let topFocusSound = musicController.topFocusSound {
+                        SummaryRow(
+                            title: languageManager.text("summary.top_focus_sound"),
+                            value: languageManager.format(
+                                "summary.top_focus_sound_value",
+                                topFocusSound.sound.displayName,
+                                formattedDuration(topFocusSound.seconds)
+                            )
+                        )
+                    }
+                    if appState.weeklyFocusGoalSeconds > 0 {
+                        SummaryRow(title: languageManager.text("summary.this_week"), value: weeklyProgressText)
+                    }
                 }
             }
         }
     }
 
+    private var weeklyProgressText: String {
+        let progress = appState.weeklyFocusProgress()
+        return languageManager.format(
+            "summary.weekly_progress_value",
+            formattedDuration(progress.completedSeconds),
+            formattedDuration(progress.goalSeconds),
+            progress.percent
+        )
+    }
+
+    private var sinceLaunchText: String {
+        let summary = appState.launchSummary()
+        return languageManager.format(
+            "summary.since_launch_value",
+            summary.completedFocusSessionsThisLaunch,
+            formattedDuration(summary.uptimeSeconds),
+            summary.completedFocusSessionsAllTime
+        )
+    }
+
     private var summaryFocusTiles: some View {
         AdaptiveMetricGrid {
             miniStatPill(
@@ -2826,14 +3855,14 @@ struct MainWindowView: View {
                 canStart: false,
                 canPause: true,
                 canResume: false,
-                canSkipBreak: true
+                canSkipBreak: appState.pomodoro.canSkipBreak
             )
         case .breakPaused:
             return PomodoroActionAvailability(
                 canStart: false,
                 canPause: false,
                 canResume: true,
-                canSkipBreak: true
+                canSkipBreak: appState.pomodoro.canSkipBreak
             )
         }
     }
@@ -3002,17 +4031,295 @@ struct MainWindowView: View {
         let currentCountdownDuration = max(60, currentConfig.countdownDuration)
         let updatedCountdownMinutes = clamp(countdownMinutes ?? currentCountdownDuration / 60, range: 0...120)
         let updatedCountdownSeconds = clamp(countdownSeconds ?? currentCountdownDuration % 60, range: 0...59)
-        let resolvedCountdownDuration = max(60, updatedCountdownMinutes * 60 + updatedCountdownSeconds)
+        let resolvedCountdownDuration = max(60, DurationConfig.seconds(fromMinutes: updatedCountdownMinutes) + updatedCountdownSeconds)
 
         appState.applyCustomDurationConfig(DurationConfig(
-            workDuration: updatedWorkMinutes * 60,
-            shortBreakDuration: updatedShortBreakMinutes * 60,
-            longBreakDuration: updatedLongBreakMinutes * 60,
+            workDuration: DurationConfig.seconds(fromMinutes: updatedWorkMinutes),
+            shortBreakDuration: DurationConfig.seconds(fromMinutes: updatedShortBreakMinutes),
+            longBreakDuration: DurationConfig.seconds(fromMinutes: updatedLongBreakMinutes),
             longBreakInterval: updatedLongBreakInterval,
             countdownDuration: resolvedCountdownDuration
         ))
     }
 
+    /// Reads the fields as typed, before they are committed, so the preview follows each keystroke.
+    private var cyclePreviewText: String {
+        let candidate = DurationConfig(
+            workDuration: DurationConfig.seconds(fromMinutes: parseMinutes(from: workMinutesText, fallback: workMinutesValue, range: 1...120)),
+            shortBreakDuration: DurationConfig.seconds(fromMinutes: parseMinutes(from: shortBreakMinutesText, fallback: shortBreakMinutesValue, range: 1...60)),
+            longBreakDuration: DurationConfig.seconds(fromMinutes: parseMinutes(from: longBreakMinutesText, fallback: longBreakMinutesValue, range: 1...90)),
+            longBreakInterval: longBreakIntervalValue,
+            countdownDuration: appState.durationConfig.countdownDuration
+        )
+        let projection = appState.pomodoro.projection(for: candidate)
+        return languageManager.format(
+            "timer.cycle_preview",
+            formattedDuration(projection.cycleSeconds),
+            formattedDuration(projection.secondsUntilLongBreak)
+        )
+    }
+
+    /// Turning rotation on cycles through every focus sound every 10 minutes.
+    private var focusSoundRotationBinding: Binding<Bool> {
+        Binding(
+            get: { musicController.focusSoundRotation != nil },
+            set: { isOn in
+                if isOn {
+                    musicController.setFocusSoundRotation(FocusSoundType.allCases, intervalMinutes: 10)
+                } else {
+                    musicController.clearFocusSoundRotation()
+                }
+            }
+        )
+    }
+
+    private var focusSoundRotationMinutesBinding: Binding<Int> {
+        Binding(
+            get: { musicController.focusSoundRotation?.intervalMinutes ?? 10 },
+            set: { minutes in
+                guard let rotation = musicController.focusSoundRotation else { return }
+                musicController.setFocusSoundRotation(rotation.sounds, intervalMinutes: minutes)
+            }
+        )
+    }
+
+    private var workHoursEnabledBinding: Binding<Bool> {
+        Binding(
+            get: { appState.workHours != nil },
+            set: { appState.workHours = $0 ? .standard : nil }
+        )
+    }
+
+    /// The settings edit one window shared by every selected day.
+    private var workHoursWindow: WorkHours.Window {
+        appState.workHours?.windows.values.first ?? WorkHours.standard.windows.values.first!
+    }
+
+    private func workHoursWeekdayBinding(_ weekday: Int) -> Binding<Bool> {
+        Binding(
+            get: { appState.workHours?.windows[weekday] != nil },
+            set: { isOn in
+                var weekdays = Set(appState.workHours.map { Array($0.windows.keys) } ?? [])
+                if isOn {
+                    weekdays.insert(weekday)
+                } else {
+                    weekdays.remove(weekday)
+                }
+                guard !weekdays.isEmpty else { return }
+                appState.workHours = WorkHours(weekdays: weekdays, window: workHoursWindow)
+            }
+        )
+    }
+
+    private func workHoursTimeBinding(isStart: Bool) -> Binding<Date> {
+        Binding(
+            get: {
+                let minute = isStart ? workHoursWindow.startMinute : workHoursWindow.endMinute
+                return Calendar.current.date(
+                    bySettingHour: minute / 60 % 24,
+                    minute: minute % 60,
+                    second: 0,
+                    of: Date()
+                ) ?? Date()
+            },
+            set: { date in
+                let components = Calendar.current.dateComponents([.hour, .minute], from: date)
+                let minute = (components.hour ?? 0) * 60 + (components.minute ?? 0)
+                let window = isStart
+                    ? WorkHours.Window(startMinute: minute, endMinute: workHoursWindow.endMinute)
+                    : WorkHours.Window(startMinute: workHoursWindow.startMinute, endMinute: minute)
+                // Ignore times that would put the end before the start.
+                guard let window, let workHours = appState.workHours else { return }
+                appState.workHours = WorkHours(weekdays: Set(workHours.windows.keys), window: window)
+            }
+        )
+    }
+
+    private var dailyFocusCapMinutesBinding: Binding<Int> {
+        Binding(
+            get: { appState.dailyFocusCapSeconds / 60 },
+            set: { appState.dailyFocusCapSeconds = max(0, $0) * 60 }
+        )
+    }
+
+    private var weeklyFocusGoalHoursBinding: Binding<Int> {
+        Binding(
+            get: { appState.weeklyFocusGoalSeconds / 3600 },
+            set: { appState.weeklyFocusGoalSeconds = max(0, $0) * 3600 }
+        )
+    }
+
+    private var dailyFocusCapLabel: String {
+        let minutes = appState.dailyFocusCapSeconds / 60
+        guard minutes > 0 else { return languageManager.text("notification.off") }
+        return minutes % 60 == 0 ? "\(minutes / 60)h" : "\(minutes / 60)h \(minutes % 60)m"
+    }
+
+    private var completionSoundLabel: String {
+        guard let path = appState.completionSoundPath else { return "Default" }
+        return URL(fileURLWithPath: path).lastPathComponent
+    }
+
+    private func saveNotificationTemplate() {
+        do {
+            try appState.setNotificationBodyTemplate(notificationTemplateText)
+            notificationTemplateError = nil
+        } catch {
+            notificationTemplateError = error.localizedDescription
+        }
+    }
+
+    private func saveIntervalProgram() {
+        do {
+            try appState.setIntervalProgram(intervalProgramText)
+            intervalProgramError = nil
+        } catch {
+            intervalProgramError = error.localizedDescription
+        }
+    }
+
+    private func chooseCompletionSound() {
+        let panel = NSOpenPanel()
+        panel.canChooseDirectories = false
+        panel.canChooseFiles = true
+        panel.allowsMultipleSelection = false
+        panel.allowedContentTypes = [.audio]
+
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+        do {
+            try appState.setCompletionSoundFile(path: url.path)
+            completionSoundError = nil
+        } catch {
+            completionSoundError = error.localizedDescription
+        }
+    }
+
+    private func importCustomPlayer() {
+        let panel = NSOpenPanel()
+        panel.canChooseDirectories = false
+        panel.canChooseFiles = true
+        panel.allowsMultipleSelection = false
+        panel.allowedContentTypes = [.json]
+
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+        let scripts: CustomPlayerScripts
+        do {
+            scripts = try JSONDecoder().decode(CustomPlayerScripts.self, from: Data(contentsOf: url))
+        } catch {
+            customPlayerError = error.localizedDescription
+            return
+        }
+        Task {
+            do {
+                try await appState.nowPlayingRouter.registerCustomPlayer(scripts)
+                customPlayerError = nil
+            } catch {
+                customPlayerError = error.localizedDescription
+            }
+        }
+    }
+
+    private func copyStateBackup() {
+        do {
+            let blob = try appState.exportStateBlob()
+            let pasteboard = NSPasteboard.general
+            pasteboard.clearContents()
+            pasteboard.setString(blob, forType: .string)
+            stateBackupMessage = "Backup copied to the clipboard."
+        } catch {
+            stateBackupMessage = error.localizedDescription
+        }
+    }
+
+    private func restoreStateBackup() {
+        guard let blob = NSPasteboard.general.string(forType: .string) else {
+            stateBackupMessage = StateSnapshotError.malformed.localizedDescription
+            return
+        }
+        do {
+            try appState.importStateBlob(blob)
+            stateBackupMessage = "Backup restored."
+        } catch {
+            stateBackupMessage = error.localizedDescription
+        }
+    }
+
+    private func resetToFactoryDefaults() {
+        let cleared = appState.resetToFactoryDefaults().map { item -> String in
+            switch item {
+            case .settings:
+                return "settings"
+            case .todayStats:
+                return "today's stats"
+            case .sessionHistory:
+                return "session history"
+            case .customMediaPlayer:
+                return "custom media player"
+            case .focusSoundUsage:
+                return "focus sound usage"
+            }
+        }
+        stateBackupMessage = "Cleared \(cleared.joined(separator: ", "))."
+    }
+
+    private func chooseStateMirrorFile() {
+        let panel = NSSavePanel()
+        panel.allowedContentTypes = [.json]
+        panel.nameFieldStringValue = "orchestrana-timer.json"
+
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+        appState.stateMirrorPath = url.path
+    }
+
+    private func exportSettingsFile() {
+        let panel = NSSavePanel()
+        panel.allowedContentTypes = [.json]
+        panel.nameFieldStringValue = "Orchestrana Settings.json"
+
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+        do {
+            try appState.exportSettings(to: url)
+            stateBackupMessage = "Settings exported."
+        } catch {
+            stateBackupMessage = error.localizedDescription
+        }
+    }
+
+    private func importSettingsFile() {
+        let panel = NSOpenPanel()
+        panel.canChooseDirectories = false
+        panel.canChooseFiles = true
+        panel.allowsMultipleSelection = false
+        panel.allowedContentTypes = [.json]
+
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+        do {
+            let skippedKeys = try appState.importSettings(from: url)
+            stateBackupMessage = skippedKeys.isEmpty
+                ? "Settings imported."
+                : "Settings imported. Skipped: \(skippedKeys.joined(separator: ", "))"
+        } catch {
+            stateBackupMessage = error.localizedDescription
+        }
+    }
+
+    private var menuBarIdleTitleBinding: Binding<String> {
+        Binding(
+            get: { appState.menuBarIdleTitle ?? languageManager.text("menu.status.ready") },
+            set: { appState.menuBarIdleTitle = $0 }
+        )
+    }
+
+    private var notificationGroupBinding: Binding<String> {
+        Binding(
+            get: { appState.notificationGroup ?? "" },
+            set: { newValue in
+                let isBlank = newValue.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+                appState.notificationGroup = isBlank ? nil : newValue
+            }
+        )
+    }
+
     private var presetSelectionBinding: Binding<PresetSelection> {
         Binding(
             get: { appState.presetSelection },
@@ -3101,9 +4408,9 @@ struct MainWindowView: View {
 
     private var dashboardPomodoroDurationConfig: DurationConfig {
         DurationConfig(
-            workDuration: dashboardWorkMinutes * 60,
-            shortBreakDuration: dashboardShortBreakMinutes * 60,
-            longBreakDuration: dashboardLongBreakMinutes * 60,
+            workDuration: DurationConfig.seconds(fromMinutes: dashboardWorkMinutes),
+            shortBreakDuration: DurationConfig.seconds(fromMinutes: dashboardShortBreakMinutes),
+            longBreakDuration: DurationConfig.seconds(fromMinutes: dashboardLongBreakMinutes),
             longBreakInterval: dashboardLongBreakInterval,
             countdownDuration: appState.durationConfig.countdownDuration
         )
@@ -3194,6 +4501,14 @@ struct MainWindowView: View {
         }
     }
 
+    /// Accepts "m:ss" or whole minutes; anything else leaves the countdown untouched.
+    private func commitCountdownRemaining() {
+        let seconds = DurationConfig.seconds(fromClockText: countdownRemainingText)
+        countdownRemainingText = ""
+        guard let seconds else { return }
+        appState.setCountdownRemaining(seconds: seconds)
+    }
+
     private func applyCountdownPreset(minutes: Int) {
         countdownMinutesText = String(minutes)
         countdownSecondsText = "00"
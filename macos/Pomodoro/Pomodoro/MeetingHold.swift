@@ -0,0 +1,64 @@
+import Combine
+import Foundation
+
+/// Pauses every timer, the focus sound and external media in one step for an ad-hoc meeting,
+/// then resumes only what was actually playing when the hold began. Timers go through
+/// `AppState.pauseAllTimers()`, so they share the menu's Resume All.
+@MainActor
+final class MeetingHoldController: ObservableObject {
+    private struct HeldState {
+        let focusSoundWasPlaying: Bool
+        let mediaWasPlaying: Bool
+    }
+
+    @Published private(set) var isActive = false
+
+    private unowned let appState: AppState
+    private let musicController: MusicController
+    private var heldState: HeldState?
+
+    init(appState: AppState, musicController: MusicController) {
+        self.appState = appState
+        self.musicController = musicController
+    }
+
+    /// Does nothing if a hold is already active, so the prior state isn't overwritten.
+    func start() {
+        guard heldState == nil else { return }
+        let held = HeldState(
+            focusSoundWasPlaying: musicController.effectiveFocusSound != .off,
+            mediaWasPlaying: appState.nowPlayingRouter.isPlaying
+        )
+        appState.pauseAllTimers()
+        if held.focusSoundWasPlaying {
+            musicController.pause()
+        }
+        if held.mediaWasPlaying {
+            appState.nowPlayingRouter.playPause()
+        }
+        heldState = held
+        isActive = true
+    }
+
+    /// Restores what `start()` paused. Anything the user changed during the hold is left alone.
+    func end() {
+        guard let held = heldState else { return }
+        heldState = nil
+        isActive = false
+        appState.resumeAllTimers()
+        if held.focusSoundWasPlaying, musicController.playbackState == .paused {
+            musicController.play()
+        }
+        if held.mediaWasPlaying, !appState.nowPlayingRouter.isPlaying {
+            appState.nowPlayingRouter.playPause()
+        }
+    }
+
+    func toggle() {
+        if isActive {
+            end()
+        } else {
+            start()
+        }
+    }
+}
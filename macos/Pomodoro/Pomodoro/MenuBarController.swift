@@ -52,6 +52,7 @@ final class MenuBarController: NSObject, NSMenuDelegate {
     private var titleTimer: Timer?
     private var lastTitleUpdateSecond: Int?
     private var cancellables: Set<AnyCancellable> = []
+    private let meetingHold: MeetingHoldController
 
     init(
         appState: AppState,
@@ -63,6 +64,7 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         self.musicController = musicController
         self.openMainWindow = openMainWindow
         self.quitHandler = quitApp
+        self.meetingHold = MeetingHoldController(appState: appState, musicController: musicController)
         if let existingItem = Self.liveStatusItem {
             NSStatusBar.system.removeStatusItem(existingItem)
             Self.liveStatusItem = nil
@@ -74,7 +76,7 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         super.init()
         configureStatusItem()
         observeStateChanges()
-        startTitleTimer()
+        updateTitleTimerForActivity()
     }
 
     deinit {
@@ -94,22 +96,36 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         menu.delegate = self
         statusItem.menu = menu
         rebuildMenu()
+        statusItem.isVisible = appState.isMenuBarItemEnabled
+    }
+
+    private func applyStatusItemVisibility(_ isEnabled: Bool) {
+        statusItem.isVisible = isEnabled
+        guard isEnabled else { return }
+        // The item was frozen while hidden; resync it with the current timer state.
+        rebuildMenu()
+        updateStatusItemLength()
+        forceTitleUpdate()
     }
 
     private func observeStateChanges() {
         appState.pomodoro.$state
+            .removeDuplicates()
             .receive(on: RunLoop.main)
             .sink { [weak self] _ in
                 self?.rebuildMenu()
                 self?.updateTitleIfNeeded()
+                self?.updateTitleTimerForActivity()
             }
             .store(in: &cancellables)
 
         appState.countdown.$state
+            .removeDuplicates()
             .receive(on: RunLoop.main)
             .sink { [weak self] _ in
                 self?.rebuildMenu()
                 self?.updateTitleIfNeeded()
+                self?.updateTitleTimerForActivity()
             }
             .store(in: &cancellables)
 
@@ -134,6 +150,99 @@ final class MenuBarController: NSObject, NSMenuDelegate {
             }
             .store(in: &cancellables)
 
+        appState.$menuBarIdleTitle
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.updateStatusItemLength()
+                self?.forceTitleUpdate()
+            }
+            .store(in: &cancellables)
+
+        appState.$isMenuBarItemEnabled
+            .dropFirst()
+            .removeDuplicates()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] isEnabled in
+                self?.applyStatusItemVisibility(isEnabled)
+            }
+            .store(in: &cancellables)
+
+        appState.$menuBarPrimaryTimer
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.forceTitleUpdate()
+            }
+            .store(in: &cancellables)
+
+        appState.$timerDisplayMode
+            .dropFirst()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.forceTitleUpdate()
+            }
+            .store(in: &cancellables)
+
+        appState.$menuBarWidth
+            .dropFirst()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.updateStatusItemLength()
+            }
+            .store(in: &cancellables)
+
+        appState.$menuBarTitleOverride
+            .dropFirst()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.updateStatusItemLength()
+                self?.forceTitleUpdate()
+                self?.rebuildMenu()
+            }
+            .store(in: &cancellables)
+
+        appState.pomodoro.$programStepIndex
+            .map { $0 != nil }
+            .removeDuplicates()
+            .dropFirst()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.updateStatusItemLength()
+            }
+            .store(in: &cancellables)
+
+        appState.$menuBarGlyphs
+            .dropFirst()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.updateStatusItemLength()
+                self?.forceTitleUpdate()
+            }
+            .store(in: &cancellables)
+
+        appState.$menuBarModeIndicator
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.updateStatusItemLength()
+                self?.forceTitleUpdate()
+            }
+            .store(in: &cancellables)
+
+        appState.$scheduledQuitDate
+            .removeDuplicates()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.rebuildMenu()
+            }
+            .store(in: &cancellables)
+
+        appState.$snoozedUntil
+            .removeDuplicates()
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.rebuildMenu()
+            }
+            .store(in: &cancellables)
+
         localizationManager.$currentLanguage
             .receive(on: RunLoop.main)
             .sink { [weak self] _ in
@@ -151,6 +260,27 @@ final class MenuBarController: NSObject, NSMenuDelegate {
                 self?.updateTitleIfNeeded()
             }
             .store(in: &cancellables)
+
+        // The finish time header is a wall-clock time.
+        NotificationCenter.default.publisher(for: .clockAdjusted, object: appState)
+            .receive(on: RunLoop.main)
+            .sink { [weak self] _ in
+                self?.rebuildMenu()
+            }
+            .store(in: &cancellables)
+    }
+
+    /// The idle title never changes on its own, so the once-a-second refresh only runs while a
+    /// timer is active. Every timer start goes through a state change, which restarts it here.
+    private func updateTitleTimerForActivity() {
+        let isIdle = appState.pomodoro.state == .idle && appState.countdown.state == .idle
+        if isIdle {
+            titleTimer?.invalidate()
+            titleTimer = nil
+            forceTitleUpdate()
+        } else if titleTimer == nil {
+            startTitleTimer()
+        }
     }
 
     private func startTitleTimer() {
@@ -177,20 +307,61 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         button.toolTip = statusTooltip()
     }
 
+    private func forceTitleUpdate() {
+        lastTitleUpdateSecond = nil
+        updateTitleIfNeeded()
+    }
+
     private func statusTitleAttributedString() -> NSAttributedString {
         let title = statusTitle()
         let font = NSFont.monospacedDigitSystemFont(ofSize: NSFont.systemFontSize, weight: .regular)
-        return NSAttributedString(string: title, attributes: [.font: font])
+        var attributes: [NSAttributedString.Key: Any] = [.font: font]
+        if appState.menuBarModeIndicator == .color, appState.menuBarTitleOverride == nil, let color = modeTitleColor() {
+            attributes[.foregroundColor] = color
+        }
+        return NSAttributedString(string: title, attributes: attributes)
+    }
+
+    private func modeTitleColor() -> NSColor? {
+        switch currentMenuMode() {
+        case .pomodoro:
+            return .systemRed
+        case .breakTime:
+            return .systemGreen
+        case .countdown, .idle:
+            return nil
+        }
+    }
+
+    /// Tag placed after the glyph when `menuBarModeIndicator` is `.textTag`, including its trailing space.
+    private func modeTag(for mode: MenuMode) -> String {
+        guard appState.menuBarModeIndicator == .textTag else { return "" }
+        switch mode {
+        case .pomodoro:
+            return localizationManager.text("menu_bar.tag.work") + " "
+        case .breakTime:
+            return localizationManager.text("menu_bar.tag.break") + " "
+        case .countdown, .idle:
+            return ""
+        }
     }
 
     private func updateStatusItemLength() {
+        // Custom titles and program step labels can be any length, so they never get the fixed timer width.
+        guard appState.menuBarWidth == .fixed,
+              appState.menuBarTitleOverride == nil,
+              appState.pomodoro.programStepIndex == nil else {
+            statusItem.length = NSStatusItem.variableLength
+            return
+        }
         let font = NSFont.monospacedDigitSystemFont(ofSize: NSFont.systemFontSize, weight: .regular)
+        let glyphs = appState.menuBarGlyphs
         let sampleTitles = [
-            "🍅 00:00",
-            "☕ 00:00",
-            "🌙 00:00",
-            "⏱ 00:00",
-            "🍅 \(localizationManager.text("menu.status.ready"))"
+            MenuBarGlyphs.join(glyphs.work, "\(modeTag(for: .pomodoro))00:00"),
+            MenuBarGlyphs.join(glyphs.shortBreak, "\(modeTag(for: .breakTime))00:00"),
+            MenuBarGlyphs.join(glyphs.longBreak, "\(modeTag(for: .breakTime))00:00"),
+            MenuBarGlyphs.join(glyphs.countdown, "00:00"),
+            idleStatusTitle()
         ]
         let maxWidth = sampleTitles
             .map { title in
@@ -203,18 +374,39 @@ final class MenuBarController: NSObject, NSMenuDelegate {
     }
 
     private func statusTitle() -> String {
-        switch currentMenuMode() {
+        if let titleOverride = appState.menuBarTitleOverride {
+            return titleOverride.text
+        }
+        let mode = currentMenuMode()
+        if mode == .pomodoro || mode == .breakTime, let step = appState.pomodoro.activeProgramStep {
+            let glyph = mode == .pomodoro ? appState.menuBarGlyphs.work : breakGlyph()
+            return MenuBarGlyphs.join(glyph, "\(step.label) \(formattedTime(appState.displayedPomodoroSeconds))")
+        }
+        switch mode {
         case .pomodoro:
-            return "🍅 \(formattedTime(appState.pomodoro.remainingSeconds))"
+            return MenuBarGlyphs.join(
+                appState.menuBarGlyphs.work,
+                "\(modeTag(for: .pomodoro))\(formattedTime(appState.displayedPomodoroSeconds))"
+            )
         case .breakTime:
-            return "\(breakEmoji()) \(formattedTime(appState.pomodoro.remainingSeconds))"
+            return MenuBarGlyphs.join(
+                breakGlyph(),
+                "\(modeTag(for: .breakTime))\(formattedTime(appState.displayedPomodoroSeconds))"
+            )
         case .countdown:
-            return "⏱ \(formattedTime(appState.countdown.remainingSeconds))"
+            return MenuBarGlyphs.join(appState.menuBarGlyphs.countdown, formattedTime(appState.displayedCountdownSeconds))
         case .idle:
-            return "🍅 \(localizationManager.text("menu.status.ready"))"
+            return idleStatusTitle()
         }
     }
 
+    private func idleStatusTitle() -> String {
+        let label = appState.menuBarIdleTitle ?? localizationManager.text("menu.status.ready")
+        let title = MenuBarGlyphs.join(appState.menuBarGlyphs.idle, label)
+        // An empty title would leave nothing to click in the menu bar.
+        return title.isEmpty ? MenuBarGlyphs.standard.idle : title
+    }
+
     private func statusTooltip() -> String {
         switch currentMenuMode() {
         case .pomodoro:
@@ -229,24 +421,38 @@ final class MenuBarController: NSObject, NSMenuDelegate {
     }
 
     private func currentMenuMode() -> MenuMode {
-        let pomodoroState = appState.pomodoro.state
-        let countdownState = appState.countdown.state
+        let countdownMode = countdownMenuMode()
+        let pomodoroMode = pomodoroMenuMode()
 
-        // Countdown has higher display priority than Pomodoro.
-        switch countdownState {
+        switch appState.menuBarPrimaryTimer {
+        case .automatic:
+            // Countdown has higher display priority than Pomodoro.
+            return countdownMode ?? pomodoroMode ?? .idle
+        case .countdown:
+            // Pinned: an idle countdown shows its configured length rather than the Pomodoro.
+            return .countdown
+        case .pomodoro:
+            return pomodoroMode ?? countdownMode ?? .idle
+        }
+    }
+
+    private func countdownMenuMode() -> MenuMode? {
+        switch appState.countdown.state {
         case .running, .paused:
             return .countdown
         case .idle, .breakRunning, .breakPaused:
-            break
+            return nil
         }
+    }
 
-        switch pomodoroState {
+    private func pomodoroMenuMode() -> MenuMode? {
+        switch appState.pomodoro.state {
         case .running, .paused:
             return .pomodoro
         case .breakRunning, .breakPaused:
             return .breakTime
         case .idle:
-            return .idle
+            return nil
         }
     }
 
@@ -254,11 +460,37 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         menu.removeAllItems()
 
         let pomodoroAvailability = pomodoroMenuActions(for: appState.pomodoro.state)
+        let isDailyFocusCapReached = appState.isDailyFocusCapReached
+        let isDetailed = appState.menuBarLayout == .detailed
         menu.addItem(sectionHeader(title: pomodoroSectionTitle()))
-        menu.addItem(actionItem(title: localizationManager.text("common.start"), action: #selector(startPomodoro), availability: pomodoroAvailability.start))
+        if isDailyFocusCapReached {
+            menu.addItem(sectionHeader(title: localizationManager.text("menu.daily_cap_reached")))
+        }
+        if let finishTime = appState.timerFinishTime() {
+            menu.addItem(sectionHeader(title: finishTimeTitle(finishTime)))
+        }
+        if isDetailed, let secondsUntilBreak = appState.pomodoro.secondsUntilNextBreak() {
+            // Rounded up, so it reads 1 min until the break starts.
+            let minutes = (secondsUntilBreak + 59) / 60
+            menu.addItem(sectionHeader(title: localizationManager.format("menu.next_break_in", minutes)))
+        }
+        menu.addItem(actionItem(
+            title: localizationManager.text("common.start"),
+            action: #selector(startPomodoro),
+            availability: isDailyFocusCapReached ? .disabled : pomodoroAvailability.start
+        ))
+        if isDetailed {
+            menu.addItem(alignedStartMenuItem(isEnabled: !isDailyFocusCapReached && pomodoroAvailability.start.isEnabled))
+        }
+        if isDailyFocusCapReached {
+            menu.addItem(actionItem(title: localizationManager.text("menu.daily_cap_override"), action: #selector(overrideDailyFocusCap)))
+        }
         menu.addItem(actionItem(
             title: pomodoroPauseTitle(),
-            action: #selector(pausePomodoro),
+            // The item does what its title says, so a click on a stale menu can't undo a pause.
+            action: appState.pomodoro.state == .paused || appState.pomodoro.state == .breakPaused
+                ? #selector(resumePomodoro)
+                : #selector(pausePomodoro),
             availability: pomodoroAvailability.pauseResume
         ))
         menu.addItem(actionItem(title: localizationManager.text("common.reset"), action: #selector(resetPomodoro), availability: pomodoroAvailability.reset))
@@ -271,10 +503,87 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         menu.addItem(actionItem(
             title: localizationManager.text("menu.skip_break"),
             action: #selector(skipBreak),
+            availability: appState.pomodoro.canSkipBreak ? pomodoroAvailability.skipBreak : .disabled
+        ))
+        menu.addItem(actionItem(
+            title: localizationManager.text("menu.extend_break"),
+            action: #selector(extendBreak),
             availability: pomodoroAvailability.skipBreak
         ))
+        if isDetailed {
+            let hasActiveTimer = appState.pomodoro.state != .idle
+                || appState.countdown.state == .running
+                || appState.countdown.state == .paused
+            let adjustAvailability: MenuActionAvailability = hasActiveTimer ? .enabled : .disabled
+            // A countdown back at its full length has no room for another minute.
+            let countdownIsFull = appState.adjustsCountdown
+                && appState.countdown.remainingSeconds >= appState.countdown.sessionDurationSeconds
+            menu.addItem(actionItem(
+                title: localizationManager.text("menu.add_minute"),
+                action: #selector(addMinuteToActiveTimer),
+                availability: countdownIsFull ? .disabled : adjustAvailability
+            ))
+            menu.addItem(actionItem(
+                title: localizationManager.text("menu.remove_minute"),
+                action: #selector(removeMinuteFromActiveTimer),
+                availability: adjustAvailability
+            ))
+        }
+        if appState.pomodoro.programStepIndex != nil {
+            menu.addItem(actionItem(
+                title: localizationManager.text("menu.program_stop"),
+                action: #selector(stopIntervalProgram)
+            ))
+        } else if appState.pomodoro.program != nil {
+            menu.addItem(actionItem(
+                title: localizationManager.text("menu.program_start"),
+                action: #selector(startIntervalProgram),
+                availability: appState.pomodoro.state == .idle ? .enabled : .disabled
+            ))
+        }
+        menu.addItem(bulkPauseMenuItem())
         menu.addItem(.separator())
+        if isDetailed {
+            addDetailedMenuSections()
+        }
+        if NSEvent.modifierFlags.contains(.option) {
+            menu.addItem(sectionHeader(title: tickPerformanceSummary()))
+            menu.addItem(actionItem(title: localizationManager.text("menu.copy_menu_structure"), action: #selector(copyMenuStructure)))
+            menu.addItem(actionItem(title: localizationManager.text("menu.copy_build_info"), action: #selector(copyBuildInfo)))
+#if DEBUG
+            let fastClockItem = actionItem(title: "Run Clock at 10×", action: #selector(toggleFastClock))
+            fastClockItem.state = appState.pomodoro.timeScale == 1 ? .off : .on
+            menu.addItem(fastClockItem)
+#endif
+        }
+        if appState.menuBarTitleOverride != nil {
+            menu.addItem(actionItem(
+                title: localizationManager.text("menu.clear_title_override"),
+                action: #selector(clearTitleOverride)
+            ))
+        }
+        menu.addItem(actionItem(title: localizationManager.text("menu.open_app"), action: #selector(openApp)))
+        if let snoozedUntil = appState.snoozedUntil {
+            menu.addItem(actionItem(
+                title: localizationManager.format("menu.end_snooze", localizationManager.timeString(from: snoozedUntil)),
+                action: #selector(endSnooze)
+            ))
+        } else {
+            menu.addItem(actionItem(title: localizationManager.text("menu.snooze_hour"), action: #selector(snoozeForHour)))
+        }
+        if let scheduledQuitDate = appState.scheduledQuitDate {
+            menu.addItem(actionItem(
+                title: localizationManager.format("menu.cancel_scheduled_quit", localizationManager.timeString(from: scheduledQuitDate)),
+                action: #selector(cancelScheduledQuit)
+            ))
+        }
+        menu.addItem(actionItem(title: localizationManager.text("menu.quit"), action: #selector(quitApp)))
+
+        statusItem.menu = menu
+    }
 
+    /// Countdown, navigation, music and sharing items, left out of the compact layout.
+    private func addDetailedMenuSections() {
         let countdownAvailability = countdownMenuActions(for: appState.countdown.state)
         let countdownMenu = NSMenu()
         countdownMenu.addItem(actionItem(
@@ -284,7 +593,7 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         ))
         countdownMenu.addItem(actionItem(
             title: countdownPauseTitle(),
-            action: #selector(pauseCountdown),
+            action: appState.countdown.state == .paused ? #selector(resumeCountdown) : #selector(pauseCountdown),
             availability: countdownAvailability.pauseResume
         ))
         countdownMenu.addItem(actionItem(
@@ -296,16 +605,25 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         countdownItem.submenu = countdownMenu
         menu.addItem(countdownItem)
         menu.addItem(.separator())
-        menu.addItem(actionItem(title: localizationManager.text("main.sidebar.flow"), action: #selector(openFlow)))
-        menu.addItem(actionItem(title: localizationManager.text("main.sidebar.tasks"), action: #selector(openTasks)))
-        menu.addItem(actionItem(title: localizationManager.text("main.sidebar.calendar"), action: #selector(openCalendar)))
+        menu.addItem(navigationItem(title: localizationManager.text("main.sidebar.flow"), action: #selector(openFlow), tab: .flow))
+        menu.addItem(navigationItem(title: localizationManager.text("main.sidebar.tasks"), action: #selector(openTasks), tab: .tasks))
+        menu.addItem(navigationItem(title: localizationManager.text("main.sidebar.calendar"), action: #selector(openCalendar), tab: .calendar))
         menu.addItem(.separator())
+        let nowPlaying = appState.nowPlayingRouter.nowPlayingText(maxLength: Self.nowPlayingMenuLength)
+        if !nowPlaying.isEmpty {
+            menu.addItem(sectionHeader(title: "♪ \(nowPlaying)"))
+        } else if let message = appState.nowPlayingRouter.controlStatus(
+            automationStatus: PermissionsManager.shared.automationStatus
+        ).message {
+            menu.addItem(sectionHeader(title: message))
+        }
         menu.addItem(musicMenuItem())
+        menu.addItem(actionItem(
+            title: localizationManager.text(meetingHold.isActive ? "menu.meeting_end" : "menu.meeting_start"),
+            action: #selector(toggleMeetingHold)
+        ))
         menu.addItem(.separator())
-        menu.addItem(actionItem(title: localizationManager.text("menu.open_app"), action: #selector(openApp)))
-        menu.addItem(actionItem(title: localizationManager.text("menu.quit"), action: #selector(quitApp)))
-
-        statusItem.menu = menu
+        menu.addItem(actionItem(title: localizationManager.text("menu.copy_status"), action: #selector(copySessionShareText)))
     }
 
     private func sectionHeader(title: String) -> NSMenuItem {
@@ -325,6 +643,63 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         return item
     }
 
+    private func bulkPauseMenuItem() -> NSMenuItem {
+        if appState.hasBulkPausedTimers {
+            return actionItem(title: localizationManager.text("menu.resume_all"), action: #selector(resumeAllTimers))
+        }
+        let pomodoroState = appState.pomodoro.state
+        let anyRunning = pomodoroState == .running || pomodoroState == .breakRunning || appState.countdown.state == .running
+        return actionItem(
+            title: localizationManager.text("menu.pause_all"),
+            action: #selector(pauseAllTimers),
+            availability: anyRunning ? .enabled : .disabled
+        )
+    }
+
+    /// Checked when `tab` is the tab the main window is showing.
+    private func navigationItem(title: String, action: Selector, tab: MainWindowView.SidebarItem) -> NSMenuItem {
+        let item = actionItem(title: title, action: action)
+        item.state = appState.activeSidebarItem == tab ? .on : .off
+        return item
+    }
+
+    /// The menu as `rebuildMenu()` builds it for the current state, one item per line: submenus are
+    /// indented two spaces, separators are "---", checked items start with "[x] " and disabled ones
+    /// end with " (disabled)". Meant for comparing the menu across timer and media states.
+    func menuStructureDescription() -> String {
+        rebuildMenu()
+        return describe(menu, depth: 0).joined(separator: "\n")
+    }
+
+    private func describe(_ menu: NSMenu, depth: Int) -> [String] {
+        let indent = String(repeating: "  ", count: depth)
+        return menu.items.flatMap { item -> [String] in
+            guard !item.isSeparatorItem else { return [indent + "---"] }
+            let check = item.state == .on ? "[x] " : ""
+            let disabled = item.isEnabled ? "" : " (disabled)"
+            let line = indent + check + item.title + disabled
+            guard let submenu = item.submenu else { return [line] }
+            return [line] + describe(submenu, depth: depth + 1)
+        }
+    }
+
+    /// Diagnostic line shown when the menu is opened with Option held.
+    private func tickPerformanceSummary() -> String {
+        // Whichever engine ticked most recently.
+        let stats = [appState.pomodoro.tickStats, appState.countdown.tickStats]
+            .max { ($0.lastTickDate ?? .distantPast) < ($1.lastTickDate ?? .distantPast) }
+        guard let stats, let lastTickDate = stats.lastTickDate else {
+            return localizationManager.text("menu.tick_summary.no_samples")
+        }
+        let secondsAgo = Int(Date().timeIntervalSince(lastTickDate))
+        return localizationManager.format(
+            "menu.tick_summary",
+            stats.averageTickDurationMilliseconds,
+            stats.lastEmitDurationMilliseconds,
+            secondsAgo
+        )
+    }
+
     private func formattedTime(_ seconds: Int) -> String {
         let clampedSeconds = max(0, seconds)
         let minutes = clampedSeconds / 60
@@ -352,6 +727,16 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         }
     }
 
+    private func finishTimeTitle(_ finishTime: AppState.TimerFinishTime) -> String {
+        let time = localizationManager.timeString(from: finishTime.date)
+        switch finishTime.source {
+        case .pomodoro:
+            return localizationManager.format("menu.finish_time.pomodoro", time)
+        case .countdown:
+            return localizationManager.format("menu.finish_time.countdown", time)
+        }
+    }
+
     private func breakMenuTitle() -> String {
         appState.pomodoroMode == .longBreak
             ? localizationManager.text("timer.long_break")
@@ -364,8 +749,8 @@ final class MenuBarController: NSObject, NSMenuDelegate {
             : localizationManager.text("menu.tooltip.break_running")
     }
 
-    private func breakEmoji() -> String {
-        appState.pomodoroMode == .longBreak ? "🌙" : "☕"
+    private func breakGlyph() -> String {
+        appState.pomodoroMode == .longBreak ? appState.menuBarGlyphs.longBreak : appState.menuBarGlyphs.shortBreak
     }
 
     private func countdownPauseTitle() -> String {
@@ -377,17 +762,44 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         }
     }
 
+    private func alignedStartMenuItem(isEnabled: Bool) -> NSMenuItem {
+        let alignMenu = NSMenu()
+        for minutes in [15, 30, 60] {
+            let item = NSMenuItem(
+                title: localizationManager.format("menu.align_start.every_minutes", minutes),
+                action: #selector(startPomodoroAlignedToClock(_:)),
+                keyEquivalent: ""
+            )
+            item.target = self
+            item.tag = minutes
+            item.isEnabled = isEnabled
+            alignMenu.addItem(item)
+        }
+        let alignItem = NSMenuItem(title: localizationManager.text("menu.align_start"), action: nil, keyEquivalent: "")
+        alignItem.submenu = alignMenu
+        alignItem.isEnabled = isEnabled
+        return alignItem
+    }
+
+    private static let nowPlayingMenuLength = 40
+
     private func musicMenuItem() -> NSMenuItem {
         let musicMenu = NSMenu()
         musicMenu.addItem(actionItem(title: musicPlayPauseTitle(), action: #selector(toggleMusicPlayback)))
         musicMenu.addItem(.separator())
 
         let ambientSoundMenu = NSMenu()
-        for sound in FocusSoundType.allCases {
-            let item = NSMenuItem(title: sound.displayName, action: #selector(selectFocusSound(_:)), keyEquivalent: "")
+        let effectiveSound = musicController.effectiveFocusSound
+        for option in musicController.availableFocusSounds() {
+            let item = NSMenuItem(title: option.displayName, action: #selector(selectFocusSound(_:)), keyEquivalent: "")
             item.target = self
-            item.state = sound == musicController.currentFocusSound ? .on : .off
-            item.representedObject = sound
+            // A dash marks a selection that is currently silent, e.g. paused for other media.
+            if option.isSelected {
+                item.state = option.sound == effectiveSound || option.sound == .off ? .on : .mixed
+            } else {
+                item.state = .off
+            }
+            item.representedObject = option.sound
             ambientSoundMenu.addItem(item)
         }
         let ambientSoundItem = NSMenuItem(title: localizationManager.text("audio.ambient_sound"), action: nil, keyEquivalent: "")
@@ -470,8 +882,45 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         appState.startPomodoro()
     }
 
+    @objc private func startPomodoroAlignedToClock(_ sender: NSMenuItem) {
+        appState.startPomodoroAlignedToClock(minutes: sender.tag)
+    }
+
+#if DEBUG
+    @objc private func toggleFastClock() {
+        appState.pomodoro.setTimeScale(appState.pomodoro.timeScale == 1 ? 10 : 1)
+    }
+#endif
+
+    @objc private func copyMenuStructure() {
+        let pasteboard = NSPasteboard.general
+        pasteboard.clearContents()
+        pasteboard.setString(menuStructureDescription(), forType: .string)
+    }
+
+    @objc private func copyBuildInfo() {
+        let pasteboard = NSPasteboard.general
+        pasteboard.clearContents()
+        pasteboard.setString(BuildInfo.current.summary, forType: .string)
+    }
+
+    @objc private func copySessionShareText() {
+        let pasteboard = NSPasteboard.general
+        pasteboard.clearContents()
+        pasteboard.setString(appState.sessionShareText(), forType: .string)
+    }
+
+    @objc private func overrideDailyFocusCap() {
+        appState.overrideDailyFocusCap()
+        rebuildMenu()
+    }
+
     @objc private func pausePomodoro() {
-        appState.togglePomodoroPause()
+        appState.pausePomodoro()
+    }
+
+    @objc private func resumePomodoro() {
+        appState.resumePomodoro()
     }
 
     @objc private func resetPomodoro() {
@@ -482,16 +931,40 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         appState.startBreak()
     }
 
+    @objc private func startIntervalProgram() {
+        appState.startIntervalProgram()
+    }
+
+    @objc private func stopIntervalProgram() {
+        appState.stopIntervalProgram()
+    }
+
     @objc private func skipBreak() {
         appState.skipBreak()
     }
 
+    @objc private func extendBreak() {
+        appState.extendBreak(byMinutes: 5)
+    }
+
+    @objc private func addMinuteToActiveTimer() {
+        appState.adjustActiveTimerRemaining(bySeconds: 60)
+    }
+
+    @objc private func removeMinuteFromActiveTimer() {
+        appState.adjustActiveTimerRemaining(bySeconds: -60)
+    }
+
     @objc private func startCountdown() {
         appState.startCountdown()
     }
 
     @objc private func pauseCountdown() {
-        appState.toggleCountdownPause()
+        appState.pauseCountdown()
+    }
+
+    @objc private func resumeCountdown() {
+        appState.resumeCountdown()
     }
 
     @objc private func resetCountdown() {
@@ -526,6 +999,34 @@ final class MenuBarController: NSObject, NSMenuDelegate {
         }
     }
 
+    @objc private func pauseAllTimers() {
+        appState.pauseAllTimers()
+    }
+
+    @objc private func resumeAllTimers() {
+        appState.resumeAllTimers()
+    }
+
+    @objc private func clearTitleOverride() {
+        appState.clearMenuBarTitleOverride()
+    }
+
+    @objc private func cancelScheduledQuit() {
+        appState.clearScheduledQuit()
+    }
+
+    @objc private func snoozeForHour() {
+        appState.snooze(until: Date().addingTimeInterval(60 * 60))
+    }
+
+    @objc private func endSnooze() {
+        appState.endSnooze()
+    }
+
+    @objc private func toggleMeetingHold() {
+        meetingHold.toggle()
+    }
+
     @objc private func selectFocusSound(_ sender: NSMenuItem) {
         guard let sound = sender.representedObject as? FocusSoundType else { return }
         if sound == .off {
@@ -0,0 +1,143 @@
+import Foundation
+
+/// Which timer the status item title follows when the Pomodoro and the countdown are both active.
+/// `.countdown` pins the title to the countdown even while only the Pomodoro runs.
+/// The menu itself always lists both timers; only the title changes.
+enum MenuBarPrimaryTimer: String, CaseIterable, Identifiable {
+    /// Countdown first, then Pomodoro — the original status item behavior.
+    case automatic
+    case pomodoro
+    case countdown
+
+    var id: String { rawValue }
+
+    var title: String {
+        switch self {
+        case .automatic:
+            return LocalizationManager.shared.text("menu_bar.primary.automatic")
+        case .pomodoro:
+            return LocalizationManager.shared.text("menu_bar.primary.pomodoro")
+        case .countdown:
+            return LocalizationManager.shared.text("menu_bar.primary.countdown")
+        }
+    }
+}
+
+/// How much the status item menu shows. Compact keeps the Pomodoro controls plus Open and Quit.
+enum MenuBarLayout: String, CaseIterable, Identifiable {
+    case compact
+    case detailed
+
+    var id: String { rawValue }
+
+    var title: String {
+        switch self {
+        case .compact:
+            return LocalizationManager.shared.text("menu_bar.layout.compact")
+        case .detailed:
+            return LocalizationManager.shared.text("menu_bar.layout.detailed")
+        }
+    }
+}
+
+/// How the status item title tells work and break apart, on top of the 🍅/☕ glyph.
+enum MenuBarModeIndicator: String, CaseIterable, Identifiable {
+    /// The glyph alone — the original status item behavior.
+    case emoji
+    /// A short localized tag such as "W" or "B" after the glyph.
+    case textTag
+    /// The time tinted per mode, which stays readable for color-blind users alongside the glyph.
+    case color
+
+    var id: String { rawValue }
+
+    var title: String {
+        switch self {
+        case .emoji:
+            return LocalizationManager.shared.text("menu_bar.indicator.emoji")
+        case .textTag:
+            return LocalizationManager.shared.text("menu_bar.indicator.text_tag")
+        case .color:
+            return LocalizationManager.shared.text("menu_bar.indicator.color")
+        }
+    }
+}
+
+/// Whether the status item keeps one width or hugs its title. Fixed stops neighboring menu bar items
+/// shifting as the time changes, e.g. from 9:59 to 10:00.
+enum MenuBarWidth: String, CaseIterable, Identifiable {
+    /// Wide enough for any timer title — the original status item behavior.
+    case fixed
+    case variable
+
+    var id: String { rawValue }
+
+    var title: String {
+        switch self {
+        case .fixed:
+            return LocalizationManager.shared.text("menu_bar.width.fixed")
+        case .variable:
+            return LocalizationManager.shared.text("menu_bar.width.variable")
+        }
+    }
+}
+
+/// Symbols leading the status item title in each mode. An empty symbol leaves just the time.
+struct MenuBarGlyphs: Equatable {
+    var work: String
+    var shortBreak: String
+    var longBreak: String
+    var countdown: String
+    var idle: String
+
+    static let standard = MenuBarGlyphs(work: "🍅", shortBreak: "☕", longBreak: "🌙", countdown: "⏱", idle: "🍅")
+
+    init(work: String, shortBreak: String, longBreak: String, countdown: String, idle: String) {
+        self.work = work
+        self.shortBreak = shortBreak
+        self.longBreak = longBreak
+        self.countdown = countdown
+        self.idle = idle
+    }
+
+    /// Reads the form written by `dictionary`. Missing modes keep their standard symbol; returns
+    /// `nil` if any value isn't a string.
+    init?(dictionary: [String: Any]) {
+        let standard = Self.standard
+        func glyph(_ key: String, _ fallback: String) -> String? {
+            guard let value = dictionary[key] else { return fallback }
+            return value as? String
+        }
+        guard let work = glyph("work", standard.work),
+              let shortBreak = glyph("shortBreak", standard.shortBreak),
+              let longBreak = glyph("longBreak", standard.longBreak),
+              let countdown = glyph("countdown", standard.countdown),
+              let idle = glyph("idle", standard.idle) else {
+            return nil
+        }
+        self.init(work: work, shortBreak: shortBreak, longBreak: longBreak, countdown: countdown, idle: idle)
+    }
+
+    var dictionary: [String: String] {
+        [
+            "work": work,
+            "shortBreak": shortBreak,
+            "longBreak": longBreak,
+            "countdown": countdown,
+            "idle": idle
+        ]
+    }
+
+    /// `glyph` and `text` separated by a space, leaving out the space when either is empty.
+    static func join(_ glyph: String, _ text: String) -> String {
+        if glyph.isEmpty { return text }
+        if text.isEmpty { return glyph }
+        return "\(glyph) \(text)"
+    }
+}
+
+/// Text shown in place of the timer title until `expiresAt`, e.g. "BUILD RUNNING 04:12".
+struct MenuBarTitleOverride: Equatable {
+    let text: String
+    let expiresAt: Date
+}
@@ -88,11 +88,81 @@ enum FocusSoundType: String, CaseIterable, Identifiable {
     }
 }
 
+/// A focus sound as presented in pickers, with its selection state resolved.
+struct FocusSoundOption: Identifiable {
+    let sound: FocusSoundType
+    let displayName: String
+    let isSelected: Bool
+
+    var id: String { sound.id }
+}
+
+/// Focus sounds to cycle through during work, switching every `intervalMinutes`.
+struct FocusSoundRotation: Equatable {
+    let sounds: [FocusSoundType]
+    let intervalMinutes: Int
+
+    /// Drops `.off` and repeated sounds. Nil unless at least two sounds remain and the interval is positive.
+    init?(sounds: [FocusSoundType], intervalMinutes: Int) {
+        var uniqueSounds: [FocusSoundType] = []
+        for sound in sounds where sound != .off && !uniqueSounds.contains(sound) {
+            uniqueSounds.append(sound)
+        }
+        guard uniqueSounds.count >= 2, intervalMinutes > 0 else { return nil }
+        self.sounds = uniqueSounds
+        self.intervalMinutes = intervalMinutes
+    }
+
+    init?(dictionary: [String: Any]) {
+        guard let rawSounds = dictionary["sounds"] as? [String],
+              let intervalMinutes = dictionary["intervalMinutes"] as? Int else { return nil }
+        self.init(sounds: rawSounds.compactMap(FocusSoundType.init(rawValue:)), intervalMinutes: intervalMinutes)
+    }
+
+    var dictionary: [String: Any] {
+        ["sounds": sounds.map(\.rawValue), "intervalMinutes": intervalMinutes]
+    }
+
+    /// The next sound in the list, or the first when `current` isn't part of the rotation.
+    func sound(after current: FocusSoundType) -> FocusSoundType {
+        guard let index = sounds.firstIndex(of: current) else { return sounds[0] }
+        return sounds[(index + 1) % sounds.count]
+    }
+}
+
 final class MusicController: ObservableObject {
     @Published private(set) var playbackState: MusicPlaybackState
     @Published private(set) var activeSource: MusicSource
     @Published var currentFocusSound: FocusSoundType
     @Published var focusVolume: Float
+    /// Seconds to crossfade when switching from one focus sound to another. 0 cuts over immediately.
+    @Published var focusSoundCrossfadeSeconds: Double {
+        didSet {
+            userDefaults.set(focusSoundCrossfadeSeconds, forKey: "music.focusCrossfadeSeconds")
+        }
+    }
+
+    /// Cycles the focus sound during work sessions; nil when rotation is off.
+    @Published private(set) var focusSoundRotation: FocusSoundRotation? {
+        didSet {
+            if let focusSoundRotation {
+                userDefaults.set(focusSoundRotation.dictionary, forKey: "music.focusRotation")
+            } else {
+                userDefaults.removeObject(forKey: "music.focusRotation")
+            }
+        }
+    }
+
+    /// Pauses the focus sound while a countdown runs; see `CountdownFocusSoundMuteController`.
+    @Published var countdownMutesFocusSound: Bool {
+        didSet {
+            userDefaults.set(countdownMutesFocusSound, forKey: "music.countdownMutesFocusSound")
+        }
+    }
+
+    /// All-time seconds each focus sound was audible during work, saved as it grows. Not published:
+    /// it changes every second of work and the views that show it already redraw with the timer.
+    private(set) var focusSoundUsageSeconds: [FocusSoundType: Int]
 
     private let userDefaults: UserDefaults
     private let ambientNoiseEngine: AmbientNoiseEngine
@@ -110,6 +180,14 @@ final class MusicController: ObservableObject {
         playbackState = storedPlayback
         activeSource = storedFocus == .off ? .none : .focusSound
         focusVolume = max(0, min(storedVolume, 1))
+        focusSoundCrossfadeSeconds = max(0, userDefaults.double(forKey: "music.focusCrossfadeSeconds"))
+        focusSoundRotation = userDefaults.dictionary(forKey: "music.focusRotation").flatMap(FocusSoundRotation.init(dictionary:))
+        countdownMutesFocusSound = userDefaults.bool(forKey: "music.countdownMutesFocusSound")
+        let storedUsage = userDefaults.dictionary(forKey: "music.focusSoundUsageSeconds") as? [String: Int] ?? [:]
+        focusSoundUsageSeconds = storedUsage.reduce(into: [:]) { usage, entry in
+            guard let sound = FocusSoundType(rawValue: entry.key), sound != .off else { return }
+            usage[sound] = max(0, entry.value)
+        }
         if storedFocus != .off, storedPlayback == .playing {
             startFocusSound(storedFocus)
         }
@@ -140,7 +218,50 @@ final class MusicController: ObservableObject {
         stopFocusSoundPlayback(keepSelection: true)
     }
 
-    func startFocusSound(_ type: FocusSoundType) {
+    /// The focus sound audible right now, as opposed to `currentFocusSound`, the user's selection.
+    /// A paused selection, including one paused while external media plays, resolves to `.off`.
+    var effectiveFocusSound: FocusSoundType {
+        guard playbackState == .playing, activeSource == .focusSound else { return .off }
+        return currentFocusSound
+    }
+
+    /// Usage for every focus sound except `.off`, with 0 for sounds never used during work.
+    func focusSoundUsage() -> [FocusSoundType: Int] {
+        FocusSoundType.allCases.reduce(into: [:]) { usage, sound in
+            guard sound != .off else { return }
+            usage[sound] = focusSoundUsageSeconds[sound] ?? 0
+        }
+    }
+
+    /// The most-used focus sound, or nil before any work time has been recorded.
+    var topFocusSound: (sound: FocusSoundType, seconds: Int)? {
+        guard let top = focusSoundUsageSeconds.max(by: { $0.value < $1.value }), top.value > 0 else { return nil }
+        return (top.key, top.value)
+    }
+
+    func recordFocusSoundUsage(_ sound: FocusSoundType, seconds: Int) {
+        guard sound != .off, seconds > 0 else { return }
+        focusSoundUsageSeconds[sound, default: 0] += seconds
+        userDefaults.set(
+            Dictionary(uniqueKeysWithValues: focusSoundUsageSeconds.map { ($0.key.rawValue, $0.value) }),
+            forKey: "music.focusSoundUsageSeconds"
+        )
+    }
+
+    /// Every focus sound in declaration order, so pickers pick up new cases automatically.
+    func availableFocusSounds() -> [FocusSoundOption] {
+        FocusSoundType.allCases.map { sound in
+            FocusSoundOption(
+                sound: sound,
+                displayName: sound.displayName,
+                isSelected: sound == currentFocusSound
+            )
+        }
+    }
+
+    /// Starts `type`, crossfading from a sound that's already playing over `fadeDuration` seconds,
+    /// or `focusSoundCrossfadeSeconds` when `nil`.
+    func startFocusSound(_ type: FocusSoundType, fadeDuration: TimeInterval? = nil) {
         guard type != .off else {
             stopFocusSound()
             return
@@ -151,12 +272,38 @@ final class MusicController: ObservableObject {
         }
         currentFocusSound = type
         ambientNoiseEngine.setVolume(focusVolume)
-        ambientNoiseEngine.play(type: type.ambientNoiseType)
+        ambientNoiseEngine.play(
+            type: type.ambientNoiseType,
+            fadeDuration: fadeDuration ?? focusSoundCrossfadeSeconds
+        )
         playbackState = .playing
         activeSource = .focusSound
         persistState()
     }
 
+    /// Returns false, leaving the current rotation alone, when `sounds` and `intervalMinutes`
+    /// don't make a valid rotation.
+    @discardableResult
+    func setFocusSoundRotation(_ sounds: [FocusSoundType], intervalMinutes: Int) -> Bool {
+        guard let rotation = FocusSoundRotation(sounds: sounds, intervalMinutes: intervalMinutes) else { return false }
+        focusSoundRotation = rotation
+        return true
+    }
+
+    func clearFocusSoundRotation() {
+        focusSoundRotation = nil
+    }
+
+    /// Restores the focus sound preferences to their defaults and forgets the usage totals.
+    /// The current selection, volume and playback are left alone.
+    func resetSettingsToDefaults() {
+        focusSoundCrossfadeSeconds = 0
+        focusSoundRotation = nil
+        countdownMutesFocusSound = false
+        focusSoundUsageSeconds = [:]
+        userDefaults.removeObject(forKey: "music.focusSoundUsageSeconds")
+    }
+
     func stopFocusSound() {
         stopFocusSoundPlayback(keepSelection: false)
         currentFocusSound = .off
@@ -29,6 +29,8 @@ enum NotificationPreference: String, CaseIterable, Identifiable {
 enum NotificationDeliveryStyle: String, CaseIterable, Identifiable {
     case system
     case inApp
+    /// `inApp` while the main window is focused, `system` otherwise.
+    case automatic
 
     var id: String { rawValue }
 
@@ -38,6 +40,8 @@ enum NotificationDeliveryStyle: String, CaseIterable, Identifiable {
             return LocalizationManager.shared.text("notification.delivery.system")
         case .inApp:
             return LocalizationManager.shared.text("notification.delivery.in_app")
+        case .automatic:
+            return LocalizationManager.shared.text("notification.delivery.automatic")
         }
     }
 
@@ -47,13 +51,19 @@ enum NotificationDeliveryStyle: String, CaseIterable, Identifiable {
             return LocalizationManager.shared.text("notification.delivery.system.detail")
         case .inApp:
             return LocalizationManager.shared.text("notification.delivery.in_app.detail")
+        case .automatic:
+            return LocalizationManager.shared.text("notification.delivery.automatic.detail")
         }
     }
 }
 
+/// An "ending soon" alert sent this long before a session or countdown reaches zero, separate from
+/// the completion alert, which still arrives at zero.
 enum ReminderPreference: String, CaseIterable, Identifiable {
     case off
+    case thirtySeconds
     case oneMinute
+    case fiveMinutes
 
     var id: String { rawValue }
 
@@ -61,8 +71,26 @@ enum ReminderPreference: String, CaseIterable, Identifiable {
         switch self {
         case .off:
             return LocalizationManager.shared.text("notification.off")
+        case .thirtySeconds:
+            return LocalizationManager.shared.text("notification.reminder.thirty_seconds")
         case .oneMinute:
             return LocalizationManager.shared.text("notification.reminder.one_minute")
+        case .fiveMinutes:
+            return LocalizationManager.shared.text("notification.reminder.five_minutes")
+        }
+    }
+
+    /// Body of the "ending soon" alert. Empty for `.off`, which sends none.
+    var remainingMessage: String {
+        switch self {
+        case .off:
+            return ""
+        case .thirtySeconds:
+            return LocalizationManager.shared.text("notification.thirty_seconds_remaining")
+        case .oneMinute:
+            return LocalizationManager.shared.text("notification.one_minute_remaining")
+        case .fiveMinutes:
+            return LocalizationManager.shared.text("notification.five_minutes_remaining")
         }
     }
 
@@ -70,8 +98,50 @@ enum ReminderPreference: String, CaseIterable, Identifiable {
         switch self {
         case .off:
             return 0
+        case .thirtySeconds:
+            return 30
         case .oneMinute:
             return 60
+        case .fiveMinutes:
+            return 5 * 60
+        }
+    }
+}
+
+enum NotificationTemplateError: LocalizedError {
+    case unknownPlaceholder(String)
+
+    var errorDescription: String? {
+        switch self {
+        case .unknownPlaceholder(let name):
+            return LocalizationManager.shared.format("notification.template.error.unknown_placeholder", name)
+        }
+    }
+}
+
+/// User-written body for session completion notifications, e.g. "Nice work, {count} down!".
+struct NotificationTemplate: Equatable {
+    static let placeholders = ["mode", "count", "next_minutes"]
+
+    let text: String
+
+    /// Throws if `text` contains a `{name}` that isn't one of `placeholders`.
+    init(_ text: String) throws {
+        for match in text.matches(of: #/\{([^{}]*)\}/#) {
+            let name = String(match.output.1)
+            guard Self.placeholders.contains(name) else {
+                throw NotificationTemplateError.unknownPlaceholder(name)
+            }
         }
+        self.text = text
+    }
+
+    /// `mode` is the session that just finished, `count` today's completed work sessions, and
+    /// `nextMinutes` the length of the session that follows.
+    func render(mode: String, count: Int, nextMinutes: Int) -> String {
+        text
+            .replacingOccurrences(of: "{mode}", with: mode)
+            .replacingOccurrences(of: "{count}", with: "\(count)")
+            .replacingOccurrences(of: "{next_minutes}", with: "\(nextMinutes)")
     }
 }
@@ -7,6 +7,43 @@
 
 import AppKit
 
+/// What the media integration can do on this Mac. Every provider drives its player with
+/// AppleScript, so all of it hinges on the Automation permission.
+struct MediaBackendInfo: Equatable {
+    let canControl: Bool
+    let canReadMetadata: Bool
+    /// No provider reports a playback position.
+    let canReadPosition: Bool
+
+    init(automationStatus: AutomationPermissionStatus) {
+        // `.unknown` still lets the first Apple Event ask for consent.
+        let isPermitted = automationStatus != .denied
+        canControl = isPermitted
+        canReadMetadata = isPermitted
+        canReadPosition = false
+    }
+}
+
+/// Why the external media controls can or can't be used right now. Every player the router polls
+/// supports play/pause and skipping, so there is no case for an unsupported source.
+enum MediaControlStatus: Equatable {
+    case ok
+    case noPlayer
+    case permissionDenied
+
+    /// Nil for `.ok`; otherwise a short explanation for a disabled control.
+    var message: String? {
+        switch self {
+        case .ok:
+            return nil
+        case .noPlayer:
+            return LocalizationManager.shared.text("media.status.no_player")
+        case .permissionDenied:
+            return LocalizationManager.shared.text("media.status.permission_denied")
+        }
+    }
+}
+
 struct NowPlayingProviderState {
     let isRunning: Bool
     let isPlaying: Bool
@@ -17,22 +17,35 @@ final class NowPlayingRouter: ObservableObject {
     @Published private(set) var sourceName: String = ""
     @Published private(set) var isPlaying: Bool = false
     @Published private(set) var isAvailable: Bool = false
+    @Published private(set) var customPlayer: CustomPlayerScripts?
 
     private let appleMusicProvider: NowPlayingProvider
     private let spotifyProvider: NowPlayingProvider
     private let qqMusicProvider: NowPlayingProvider
+    private var customProvider: CustomScriptProvider?
+    private let userDefaults: UserDefaults
+    private static let customPlayerDefaultsKey = "nowPlaying.customPlayer"
     private var pollTask: Task<Void, Never>?
     private var activeProvider: NowPlayingProvider?
+    /// What the player reported, before `artist` fills in "Unknown Artist".
+    private var reportedArtist = ""
 
     init(
         appleMusicProvider: NowPlayingProvider,
         spotifyProvider: NowPlayingProvider,
         qqMusicProvider: NowPlayingProvider,
+        userDefaults: UserDefaults = .standard,
         startPolling: Bool = false
     ) {
         self.appleMusicProvider = appleMusicProvider
         self.spotifyProvider = spotifyProvider
         self.qqMusicProvider = qqMusicProvider
+        self.userDefaults = userDefaults
+        if let data = userDefaults.data(forKey: Self.customPlayerDefaultsKey),
+           let scripts = try? JSONDecoder().decode(CustomPlayerScripts.self, from: data) {
+            customPlayer = scripts
+            customProvider = CustomScriptProvider(scripts: scripts)
+        }
 
         if startPolling {
             startPollingLoop()
@@ -73,6 +86,37 @@ final class NowPlayingRouter: ObservableObject {
         }
     }
 
+    /// Validates and stores scripts for a player without a built-in provider. It is polled after
+    /// Apple Music and Spotify, and replaces any previously registered custom player.
+    func registerCustomPlayer(_ scripts: CustomPlayerScripts) async throws {
+        try await CustomScriptProvider.validate(scripts)
+        let data = try JSONEncoder().encode(scripts)
+        userDefaults.set(data, forKey: Self.customPlayerDefaultsKey)
+        customPlayer = scripts
+        customProvider = CustomScriptProvider(scripts: scripts)
+    }
+
+    func removeCustomPlayer() {
+        userDefaults.removeObject(forKey: Self.customPlayerDefaultsKey)
+        customPlayer = nil
+        customProvider = nil
+    }
+
+    /// Checks the Automation permission before the player, since without it no player can be seen.
+    func controlStatus(automationStatus: AutomationPermissionStatus) -> MediaControlStatus {
+        guard MediaBackendInfo(automationStatus: automationStatus).canControl else { return .permissionDenied }
+        return isAvailable ? .ok : .noPlayer
+    }
+
+    /// "Artist — Title" for compact displays, just the title when the player reports no artist, or
+    /// an empty string when nothing is available. Longer text is cut to `maxLength` with an ellipsis.
+    func nowPlayingText(maxLength: Int? = nil) -> String {
+        guard isAvailable else { return "" }
+        let text = reportedArtist.isEmpty ? title : "\(reportedArtist) — \(title)"
+        guard let maxLength, maxLength > 0, text.count > maxLength else { return text }
+        return String(text.prefix(maxLength - 1)) + "…"
+    }
+
     func startPollingIfNeeded() {
         guard pollTask == nil else { return }
         startPollingLoop()
@@ -95,15 +139,18 @@ final class NowPlayingRouter: ObservableObject {
 
         let apple = await appleProvider.fetchState()
         let spotify = await spotifyProvider.fetchState()
+        var candidates: [(state: NowPlayingProviderState, provider: NowPlayingProvider)] = [
+            (apple, appleProvider),
+            (spotify, spotifyProvider)
+        ]
+        if let customProvider {
+            candidates.append((await customProvider.fetchState(), customProvider))
+        }
 
-        if apple.isPlaying {
-            apply(state: apple, provider: appleProvider)
-        } else if spotify.isPlaying {
-            apply(state: spotify, provider: spotifyProvider)
-        } else if apple.isRunning {
-            apply(state: apple, provider: appleProvider)
-        } else if spotify.isRunning {
-            apply(state: spotify, provider: spotifyProvider)
+        if let playing = candidates.first(where: { $0.state.isPlaying }) {
+            apply(state: playing.state, provider: playing.provider)
+        } else if let running = candidates.first(where: { $0.state.isRunning }) {
+            apply(state: running.state, provider: running.provider)
         } else {
             clearState()
         }
@@ -113,6 +160,7 @@ final class NowPlayingRouter: ObservableObject {
         activeProvider = provider
         title = state.title.isEmpty ? "Unknown Track" : state.title
         artist = state.artist.isEmpty ? "Unknown Artist" : state.artist
+        reportedArtist = state.artist
         artwork = state.artwork
         sourceName = provider.sourceName
         isPlaying = state.isPlaying
@@ -123,6 +171,7 @@ final class NowPlayingRouter: ObservableObject {
         activeProvider = nil
         title = ""
         artist = ""
+        reportedArtist = ""
         artwork = nil
         sourceName = ""
         isPlaying = false
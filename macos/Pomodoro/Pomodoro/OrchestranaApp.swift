@@ -89,6 +89,7 @@ struct OrchestranaApp: App {
 
         let appState = AppState()
         let musicController = MusicController(ambientNoiseEngine: appState.ambientNoiseEngine)
+        appState.musicController = musicController
         let externalMonitor = ExternalAudioMonitor()
         let externalController = ExternalPlaybackController()
         _appState = StateObject(wrappedValue: appState)
@@ -11,6 +11,13 @@ final class SharedEventStore {
     private init() {}
 }
 
+/// Whether macOS lets the app send Apple Events, which media control relies on.
+enum AutomationPermissionStatus {
+    case granted
+    case denied
+    case unknown
+}
+
 /// Centralized permissions manager for Notifications, Calendar, and Reminders.
 /// Provides authorization status checks and system settings opening.
 @MainActor
@@ -19,10 +26,14 @@ final class PermissionsManager: ObservableObject {
     
     // System Settings URL
     private static let systemSettingsURL = "x-apple.systempreferences:com.apple.preference.security?Privacy"
+    private static let automationSettingsURL = "x-apple.systempreferences:com.apple.preference.security?Privacy_Automation"
+    /// errAEEventNotPermitted: the user turned off Automation for this app.
+    private static let appleEventNotPermittedError = -1743
     
     @Published var notificationStatus: UNAuthorizationStatus = .notDetermined
     @Published var calendarStatus: EKAuthorizationStatus = .notDetermined
     @Published var remindersStatus: EKAuthorizationStatus = .notDetermined
+    @Published private(set) var automationStatus: AutomationPermissionStatus = .unknown
     
     // Alert state for denied permissions
     @Published var showCalendarDeniedAlert = false
@@ -62,6 +73,22 @@ final class PermissionsManager: ObservableObject {
         updateRemindersStatus(EKEventStore.authorizationStatus(for: .reminder))
     }
     
+    /// Sends a read-only query to System Events. Not part of `refreshAllStatuses()` because the
+    /// first query shows the Automation consent prompt.
+    func refreshAutomationStatus() async {
+        let result = await AppleScriptRunner.runReportingError(
+            "tell application \"System Events\" to get name of first process"
+        )
+        switch result {
+        case .success:
+            automationStatus = .granted
+        case .failure(let failure):
+            let isDenial = failure.number == Self.appleEventNotPermittedError
+                || failure.message.localizedCaseInsensitiveContains("not allowed")
+            automationStatus = isDenial ? .denied : .unknown
+        }
+    }
+
     // MARK: - Permission Requests
     
     /// Request notification permission - shows system dialog if notDetermined, alert if denied/restricted
@@ -204,6 +231,12 @@ final class PermissionsManager: ObservableObject {
         }
     }
 
+    func openAutomationSettings() {
+        if let url = URL(string: Self.automationSettingsURL) {
+            NSWorkspace.shared.open(url)
+        }
+    }
+
     // MARK: - Private helpers
 
     private func requestCalendarAccess() async -> Bool {
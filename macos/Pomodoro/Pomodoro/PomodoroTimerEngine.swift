@@ -28,36 +28,128 @@ final class PomodoroTimerEngine: ObservableObject {
     @Published private(set) var currentMode: CurrentMode = .idle
     @Published private(set) var completedWorkSessions: Int = 0
 
+    /// Consulted when a break finishes. Returning `false` stops at idle instead of
+    /// automatically starting the next work session.
+    var shouldAutoStartWork: () -> Bool = { true }
+    /// Work sessions started automatically since the user last started, paused, resumed, skipped or reset.
+    private(set) var consecutiveAutoStarts = 0
+    /// Whether a work session still counts toward the long-break interval when the short break
+    /// that follows it is skipped. With `false`, skipping a short break takes that session back
+    /// out of the cycle, so an interval of 4 needs four breaks actually taken (or run to the end).
+    /// Skipping a long break always starts a fresh cycle, and ending work early with `startBreak()`
+    /// never counts the session.
+    var skipCountsTowardCycle = true
+    /// Seconds to wait at 00:00 before the next work session starts on its own.
+    var autoStartDelayWorkSeconds = 0
+    /// Seconds to wait at 00:00 before a break starts after a work session.
+    var autoStartDelayBreakSeconds = 0
+
+    /// Seconds added to each short break after the first in a cycle, e.g. 60 for 5, 6 and 7 minutes.
+    /// Short breaks never grow past the long break. 0 keeps them fixed.
+    var shortBreakGrowthSeconds = 0
+
+    /// Seconds a break must run before the user may skip it; see `canSkipBreak`. 0 allows skipping at once.
+    var minimumBreakSeconds = 0
+
+    /// Routine run by `startProgram()` in place of the usual work/break rules.
+    @Published private(set) var program: IntervalProgram?
+    /// Position of the running program step, counting across repeats. Nil when no program runs.
+    @Published private(set) var programStepIndex: Int?
+
+    var activeProgramStep: IntervalProgram.Step? {
+        programStepIndex.flatMap { program?.step(at: $0) }
+    }
+
+    /// Full length of the current session, including any break extension.
+    private(set) var sessionDurationSeconds: Int
+
+    var elapsedSeconds: Int {
+        max(0, sessionDurationSeconds - remainingSeconds)
+    }
+
     private var durationConfig: DurationConfig
     private var timer: Timer?
+    /// Fires the session transition once the auto-start delay has passed. Cleared by `stopTimer()`,
+    /// so pausing, skipping or resetting during the delay cancels it.
+    private var pendingCompletion: Timer?
+    private(set) var tickStats = TickPerformanceStats()
+    /// Timer seconds per real second. Always 1 outside debug builds; see `setTimeScale(_:)`.
+    private(set) var timeScale: Double = 1
+    private var accumulatedPausedSeconds: TimeInterval = 0
+    private var pausedAt: Date?
+
+    /// Wall-clock seconds the current session has spent paused, including an ongoing pause.
+    /// Remains readable while observers handle the state change that ends the session.
+    var currentSessionPausedSeconds: Int {
+        // Clamped so a clock set back during the pause can't count negative time.
+        let ongoingPause = pausedAt.map { max(0, Date().timeIntervalSince($0)) } ?? 0
+        return Int((accumulatedPausedSeconds + ongoingPause).rounded())
+    }
 
     init(
         durationConfig: DurationConfig = .standard
     ) {
         self.durationConfig = durationConfig
         self.remainingSeconds = durationConfig.workDuration
+        self.sessionDurationSeconds = durationConfig.workDuration
         updateCurrentMode()
     }
 
     func updateConfiguration(durationConfig: DurationConfig) {
+        let previousInterval = self.durationConfig.longBreakInterval
         self.durationConfig = durationConfig
+        // Progress toward the long break is kept, but never reaches a shortened interval: the long
+        // break then follows the next completed work session rather than a whole extra cycle later.
+        if durationConfig.longBreakInterval < previousInterval {
+            completedWorkSessions = min(completedWorkSessions, durationConfig.longBreakInterval - 1)
+        }
 
         if state == .idle {
             remainingSeconds = durationConfig.workDuration
+            sessionDurationSeconds = remainingSeconds
             mode = .work
             updateCurrentMode()
         }
     }
 
-    func start() {
+    /// Starts a work session. `firstSessionSeconds` overrides the length of this session only;
+    /// later sessions use the configured work duration.
+    func start(firstSessionSeconds: Int? = nil) {
         guard state == .idle else { return }
-        remainingSeconds = durationConfig.workDuration
+        remainingSeconds = firstSessionSeconds.map(DurationConfig.clampedDuration) ?? durationConfig.workDuration
+        sessionDurationSeconds = remainingSeconds
+        consecutiveAutoStarts = 0
+        resetPauseTracking()
         state = .running
         mode = .work
         updateCurrentMode()
         startTimer()
     }
 
+    /// Replaces the program `startProgram()` runs. Returns false while a program is running.
+    @discardableResult
+    func loadProgram(_ program: IntervalProgram?) -> Bool {
+        guard programStepIndex == nil else { return false }
+        self.program = program
+        return true
+    }
+
+    /// Runs the loaded program from its first step. Each step starts on its own when the previous one
+    /// ends, and the timer returns to idle after the last.
+    func startProgram() {
+        guard state == .idle, program != nil else { return }
+        consecutiveAutoStarts = 0
+        beginProgramStep(at: 0)
+    }
+
+    /// Ends the running program early and returns to an idle work session.
+    func stopProgram() {
+        guard programStepIndex != nil else { return }
+        stopTimer()
+        programStepIndex = nil
+        returnToIdleWork()
+    }
+
     func pause() {
         switch state {
         case .running:
@@ -67,6 +159,8 @@ final class PomodoroTimerEngine: ObservableObject {
         case .idle, .paused, .breakPaused:
             return
         }
+        pausedAt = Date()
+        consecutiveAutoStarts = 0
         updateCurrentMode()
         stopTimer()
     }
@@ -80,19 +174,72 @@ final class PomodoroTimerEngine: ObservableObject {
         case .idle, .running, .breakRunning:
             return
         }
+        if let pausedAt {
+            accumulatedPausedSeconds += max(0, Date().timeIntervalSince(pausedAt))
+            self.pausedAt = nil
+        }
+        consecutiveAutoStarts = 0
         updateCurrentMode()
         startTimer()
     }
 
     func reset() {
         stopTimer()
+        programStepIndex = nil
         state = .idle
         remainingSeconds = durationConfig.workDuration
+        sessionDurationSeconds = remainingSeconds
         mode = .work
         completedWorkSessions = 0
+        consecutiveAutoStarts = 0
+        resetPauseTracking()
+        updateCurrentMode()
+    }
+
+    /// Reloads the current session at its full configured length (or its program step's length) and
+    /// leaves it paused. Mode, cycle progress and any running program are kept. Does nothing when idle.
+    func restartSession() {
+        let duration: Int
+        if let activeProgramStep {
+            duration = activeProgramStep.seconds
+        } else {
+            switch mode {
+            case .work:
+                duration = durationConfig.workDuration
+            case .breakTime:
+                duration = shortBreakSeconds(forBreakNumber: completedWorkSessions)
+            case .longBreak:
+                duration = durationConfig.longBreakDuration
+            }
+        }
+        switch state {
+        case .running, .paused:
+            state = .paused
+        case .breakRunning, .breakPaused:
+            state = .breakPaused
+        case .idle:
+            return
+        }
+        stopTimer()
+        remainingSeconds = duration
+        sessionDurationSeconds = duration
+        consecutiveAutoStarts = 0
+        resetPauseTracking()
+        pausedAt = Date()
         updateCurrentMode()
     }
 
+    /// Whether the user may end the current break early. `skipBreak()` itself doesn't check this,
+    /// so app-driven skips such as finishing an execution plan still go through.
+    var canSkipBreak: Bool {
+        switch state {
+        case .breakRunning, .breakPaused:
+            return elapsedSeconds >= minimumBreakSeconds
+        case .idle, .running, .paused:
+            return false
+        }
+    }
+
     func skipBreak() {
         switch state {
         case .breakRunning, .breakPaused:
@@ -101,13 +248,15 @@ final class PomodoroTimerEngine: ObservableObject {
             return
         }
         stopTimer()
-        state = .idle
-        remainingSeconds = durationConfig.workDuration
-        if mode == .longBreak {
-            completedWorkSessions = 0
+        consecutiveAutoStarts = 0
+        if let programStepIndex {
+            beginProgramStep(at: programStepIndex + 1)
+            return
         }
-        mode = .work
-        updateCurrentMode()
+        if mode == .breakTime, !skipCountsTowardCycle {
+            completedWorkSessions = max(0, completedWorkSessions - 1)
+        }
+        returnToIdleWork()
     }
 
     func startBreak() {
@@ -117,14 +266,203 @@ final class PomodoroTimerEngine: ObservableObject {
         case .idle, .breakRunning, .breakPaused:
             return
         }
+        // A program decides its own breaks.
+        guard programStepIndex == nil else { return }
         stopTimer()
+        consecutiveAutoStarts = 0
         beginBreak(isLongBreak: isLongBreakDue())
         startTimer()
     }
 
+    /// Adds time to the current break, pushing back the automatic start of the next work session.
+    /// A paused break stays paused. Does nothing outside a break.
+    func extendBreak(bySeconds seconds: Int) {
+        switch state {
+        case .breakRunning, .breakPaused:
+            break
+        case .idle, .running, .paused:
+            return
+        }
+        guard seconds > 0 else { return }
+        remainingSeconds += seconds
+        sessionDurationSeconds += seconds
+        if pendingCompletion != nil {
+            // Extended while waiting to start work: keep the break counting down instead.
+            startTimer()
+        }
+    }
+
+    /// Moves the current session's remaining time by `delta` seconds. Going past the session length
+    /// lengthens the session; reaching zero completes it as if it had run out.
+    func adjustRemaining(bySeconds delta: Int) {
+        let adjusted = max(0, remainingSeconds + delta)
+        guard state != .idle, adjusted != remainingSeconds else { return }
+        remainingSeconds = adjusted
+        sessionDurationSeconds = max(sessionDurationSeconds, remainingSeconds)
+        if remainingSeconds == 0 {
+            completeAfterAutoStartDelay()
+        } else if pendingCompletion != nil {
+            // Added time while waiting out the auto-start delay: count it down instead.
+            startTimer()
+        }
+    }
+
+    /// Sets the same delay before both breaks and work sessions.
+    func setAutoStartDelay(_ seconds: Int) {
+        autoStartDelayWorkSeconds = max(0, seconds)
+        autoStartDelayBreakSeconds = max(0, seconds)
+    }
+
+#if DEBUG
+    /// Runs the timer `scale` times faster, e.g. 10 to finish a 25-minute session in 2.5 minutes
+    /// for a demo. The timer still moves one second per tick, so every transition fires once.
+    func setTimeScale(_ scale: Double) {
+        timeScale = min(max(scale, 0.1), 100)
+        if timer != nil {
+            startTimer()
+        }
+    }
+#endif
+
+    /// Nil unless a finished session is waiting out its auto-start delay. Pausing, skipping or
+    /// resetting during the delay cancels it, so those states report nil too.
+    func autoStartGraceState(now: Date = Date()) -> AutoStartGraceState? {
+        guard let pendingCompletion, pendingCompletion.isValid else { return nil }
+        let upcomingMode: Mode
+        if let programStepIndex {
+            // After the last step the program ends on an idle work session.
+            upcomingMode = program?.step(at: programStepIndex + 1)?.mode ?? .work
+        } else {
+            switch state {
+            case .running, .paused:
+                // `handleCompletion()` counts this session before choosing the break.
+                let completed = completedWorkSessions + 1
+                upcomingMode = completed % durationConfig.longBreakInterval == 0 ? .longBreak : .breakTime
+            case .breakRunning, .breakPaused, .idle:
+                upcomingMode = .work
+            }
+        }
+        let secondsLeft = max(0, pendingCompletion.fireDate.timeIntervalSince(now))
+        return AutoStartGraceState(upcomingMode: upcomingMode, secondsLeft: Int(secondsLeft.rounded(.up)))
+    }
+
+    /// Seconds from now until the next break starts: what's left of the work session plus the
+    /// break's auto-start delay, or just the rest of the delay once the session has reached 00:00.
+    /// Nil when idle, during a break, and when the next program step isn't a break.
+    func secondsUntilNextBreak(now: Date = Date()) -> Int? {
+        switch state {
+        case .running, .paused:
+            break
+        case .idle, .breakRunning, .breakPaused:
+            return nil
+        }
+        if let programStepIndex {
+            guard let nextStep = program?.step(at: programStepIndex + 1), nextStep.mode != .work else { return nil }
+        }
+        if let grace = autoStartGraceState(now: now) {
+            return grace.secondsLeft
+        }
+        return remainingSeconds + autoStartDelayBreakSeconds
+    }
+
+    /// Cheap snapshot of the engine's clock; does not change any state.
+    func clockReading(now: Date = Date()) -> TimerClockReading {
+        let isCountingDown = timer != nil && (state == .running || state == .breakRunning)
+        return TimerClockReading(
+            now: now,
+            activeDeadline: isCountingDown ? now.addingTimeInterval(TimeInterval(remainingSeconds)) : nil
+        )
+    }
+
+    var compactState: CompactTimerState {
+        let mode: UInt8
+        switch currentMode {
+        case .idle:
+            mode = 0
+        case .work:
+            mode = 1
+        case .break:
+            mode = 2
+        case .longBreak:
+            mode = 3
+        }
+        let permille = sessionDurationSeconds > 0 ? elapsedSeconds * 1000 / sessionDurationSeconds : 0
+        return CompactTimerState(
+            mode: mode,
+            isRunning: state == .running || state == .breakRunning,
+            remainingSeconds: UInt16(min(remainingSeconds, Int(UInt16.max))),
+            progressPermille: UInt16(min(max(permille, 0), 1000))
+        )
+    }
+
+    var cycleProgress: CycleProgress {
+        let total = durationConfig.longBreakInterval
+        if state != .idle, mode == .longBreak {
+            // The counter already restarted when the long break began; keep the cycle shown as full.
+            return CycleProgress(completed: total, total: total)
+        }
+        return CycleProgress(completed: min(completedWorkSessions, total), total: total)
+    }
+
+    /// Length of the short break running now or, outside a break, the one after the next work session.
+    var currentShortBreakSeconds: Int {
+        switch state {
+        case .breakRunning, .breakPaused:
+            return shortBreakSeconds(forBreakNumber: completedWorkSessions)
+        case .idle, .running, .paused:
+            return shortBreakSeconds(forBreakNumber: completedWorkSessions + 1)
+        }
+    }
+
+    /// `number` counts from 1 for the break after the first work session of the cycle.
+    private func shortBreakSeconds(forBreakNumber number: Int) -> Int {
+        shortBreakSeconds(forBreakNumber: number, config: durationConfig)
+    }
+
+    private func shortBreakSeconds(forBreakNumber number: Int, config: DurationConfig) -> Int {
+        let base = config.shortBreakDuration
+        guard shortBreakGrowthSeconds > 0, number > 1 else { return base }
+        let grown = base + shortBreakGrowthSeconds * (number - 1)
+        return min(grown, max(base, config.longBreakDuration))
+    }
+
+    /// Projects the cycle for `config` from the current progress without changing any state.
+    /// The running session keeps its remaining time, as `updateConfiguration` would leave it.
+    func projection(for config: DurationConfig) -> CycleProjection {
+        let interval = config.longBreakInterval
+        let focusSeconds = interval * config.workDuration
+        // Short breaks are numbered from 1 and may grow; see `shortBreakGrowthSeconds`.
+        func shortBreakTotal(_ numbers: Range<Int>) -> Int {
+            numbers.reduce(0) { $0 + shortBreakSeconds(forBreakNumber: $1, config: config) }
+        }
+        let breakSeconds = shortBreakTotal(1..<interval) + config.longBreakDuration
+        let completedInCycle = completedWorkSessions % interval
+        // Work sessions still needed before the long break, counting one in progress.
+        let sessionsLeft = interval - completedInCycle
+        // The short breaks after those sessions, not counting one in progress.
+        let shortBreaksLeft = shortBreakTotal((completedInCycle + 1)..<interval)
+        let untilLongBreak: Int
+        switch state {
+        case .idle:
+            untilLongBreak = sessionsLeft * config.workDuration + shortBreaksLeft
+        case .running, .paused:
+            untilLongBreak = remainingSeconds + (sessionsLeft - 1) * config.workDuration + shortBreaksLeft
+        case .breakRunning, .breakPaused:
+            untilLongBreak = mode == .longBreak
+                ? 0
+                : remainingSeconds + sessionsLeft * config.workDuration + shortBreaksLeft
+        }
+        return CycleProjection(
+            cycleSeconds: focusSeconds + breakSeconds,
+            focusSecondsPerCycle: focusSeconds,
+            breakSecondsPerCycle: breakSeconds,
+            secondsUntilLongBreak: untilLongBreak
+        )
+    }
+
     private func startTimer() {
         stopTimer()
-        timer = Timer.scheduledTimer(withTimeInterval: 1.0, repeats: true) { [weak self] _ in
+        timer = Timer.scheduledTimer(withTimeInterval: 1.0 / timeScale, repeats: true) { [weak self] _ in
             self?.tick()
         }
     }
@@ -132,53 +470,144 @@ final class PomodoroTimerEngine: ObservableObject {
     private func stopTimer() {
         timer?.invalidate()
         timer = nil
+        pendingCompletion?.invalidate()
+        pendingCompletion = nil
     }
 
     private func tick() {
+        let tickStart = DispatchTime.now()
+        defer { tickStats.recordTick(startedAt: tickStart) }
         guard remainingSeconds > 0 else {
             handleCompletion()
             return
         }
 
+        let emitStart = DispatchTime.now()
         remainingSeconds -= 1
+        tickStats.recordEmit(startedAt: emitStart)
 
         if remainingSeconds == 0 {
+            completeAfterAutoStartDelay()
+        }
+    }
+
+    /// Holds at 00:00 for the delay configured for the upcoming session, then transitions. A timer
+    /// resumed from a pause during the delay transitions on its next tick without waiting again.
+    private func completeAfterAutoStartDelay() {
+        let delay: Int
+        switch state {
+        case .running, .paused:
+            delay = autoStartDelayBreakSeconds
+        case .breakRunning, .breakPaused:
+            delay = autoStartDelayWorkSeconds
+        case .idle:
+            delay = 0
+        }
+        guard delay > 0 else {
             handleCompletion()
+            return
+        }
+        stopTimer()
+        pendingCompletion = Timer.scheduledTimer(withTimeInterval: TimeInterval(delay) / timeScale, repeats: false) { [weak self] _ in
+            self?.pendingCompletion = nil
+            self?.handleCompletion()
         }
     }
 
     private func handleCompletion() {
+        if let programStepIndex {
+            if program?.step(at: programStepIndex + 1)?.mode == .work {
+                guard shouldAutoStartWork() else {
+                    stopProgram()
+                    return
+                }
+                consecutiveAutoStarts += 1
+            }
+            beginProgramStep(at: programStepIndex + 1)
+            return
+        }
         switch state {
         case .breakRunning, .breakPaused:
             stopTimer()
+            guard shouldAutoStartWork() else {
+                returnToIdleWork()
+                return
+            }
             // Break finished → automatically begin next work session (Pomodoro continues without user action).
             // This keeps the classic Pomodoro cycle flowing unless the user manually stops.
             state = .running
-            remainingSeconds = durationConfig.workDuration
+            sessionDurationSeconds = durationConfig.workDuration
+            remainingSeconds = sessionDurationSeconds
+            consecutiveAutoStarts += 1
             if mode == .longBreak {
                 completedWorkSessions = 0
             }
             mode = .work
+            resetPauseTracking()
             updateCurrentMode()
             startTimer()
         case .running, .paused:
             completedWorkSessions += 1
             beginBreak(isLongBreak: isLongBreakDue())
+            if timer == nil {
+                // The tick timer was stopped for the auto-start delay.
+                startTimer()
+            }
         case .idle:
             break
         }
     }
 
+    private func returnToIdleWork() {
+        state = .idle
+        remainingSeconds = durationConfig.workDuration
+        sessionDurationSeconds = remainingSeconds
+        if mode == .longBreak {
+            completedWorkSessions = 0
+        }
+        mode = .work
+        resetPauseTracking()
+        updateCurrentMode()
+    }
+
     private func beginBreak(isLongBreak: Bool) {
         state = .breakRunning
         mode = isLongBreak ? .longBreak : .breakTime
-        remainingSeconds = isLongBreak ? durationConfig.longBreakDuration : durationConfig.shortBreakDuration
+        // Length first, so observers of `remainingSeconds` in an already running state, such as the
+        // ending-soon reminder, see the new session's length rather than the last one's.
+        sessionDurationSeconds = isLongBreak
+            ? durationConfig.longBreakDuration
+            : shortBreakSeconds(forBreakNumber: completedWorkSessions)
+        remainingSeconds = sessionDurationSeconds
         if isLongBreak {
             completedWorkSessions = 0
         }
+        resetPauseTracking()
         updateCurrentMode()
     }
 
+    /// Starts the step at `index`, or ends the program when there are no steps left.
+    private func beginProgramStep(at index: Int) {
+        guard let step = program?.step(at: index) else {
+            stopProgram()
+            return
+        }
+        programStepIndex = index
+        mode = step.mode
+        sessionDurationSeconds = step.seconds
+        remainingSeconds = step.seconds
+        state = step.mode == .work ? .running : .breakRunning
+        resetPauseTracking()
+        updateCurrentMode()
+        startTimer()
+    }
+
+    /// Called after the state change that ends a session, so observers logging it still see its pauses.
+    private func resetPauseTracking() {
+        accumulatedPausedSeconds = 0
+        pausedAt = nil
+    }
+
     private func isLongBreakDue() -> Bool {
         // Choose a long break on exact interval boundaries without resetting the counter.
         return completedWorkSessions > 0
@@ -232,6 +232,22 @@ enum ProductivityAnalyticsConfiguration {
     static let defaultShortSessionThreshold = 15 * 60
 }
 
+/// Focus time logged in the current week against a weekly target.
+struct WeeklyFocusProgress: Equatable {
+    let goalSeconds: Int
+    let completedSeconds: Int
+
+    var remainingSeconds: Int {
+        max(0, goalSeconds - completedSeconds)
+    }
+
+    /// 0–100, capped once the goal is met. 0 when no goal is set.
+    var percent: Int {
+        guard goalSeconds > 0 else { return 0 }
+        return min(100, completedSeconds * 100 / goalSeconds)
+    }
+}
+
 @MainActor
 final class ProductivityAnalyticsStore: ObservableObject {
     static let shared = ProductivityAnalyticsStore()
@@ -239,7 +255,9 @@ final class ProductivityAnalyticsStore: ObservableObject {
     @Published private(set) var dailyAggregates: [DailyProductivityAggregate] = []
 
     private var aggregateIndex: [Date: DailyProductivityAggregate] = [:]
-    private let fileURL: URL
+    let fileURL: URL
+    /// False for stores from `inMemory()`, which never read or write `fileURL`.
+    private let persists: Bool
     private let decoder = JSONDecoder()
     private let encoder = JSONEncoder()
 
@@ -249,9 +267,20 @@ final class ProductivityAnalyticsStore: ObservableObject {
         let dir = supportDir.appendingPathComponent("PomodoroApp", isDirectory: true)
         try? FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
         fileURL = dir.appendingPathComponent("productivity_analytics_cache.json")
+        persists = true
         load()
     }
 
+    private init(fileURL: URL, persists: Bool) {
+        self.fileURL = fileURL
+        self.persists = persists
+    }
+
+    /// An empty store that is never written to disk, e.g. for tests.
+    static func inMemory() -> ProductivityAnalyticsStore {
+        ProductivityAnalyticsStore(fileURL: URL(fileURLWithPath: "/dev/null"), persists: false)
+    }
+
     func rebuild(from records: [SessionRecord], calendar: Calendar = .current) {
         aggregateIndex.removeAll(keepingCapacity: true)
 
@@ -367,6 +396,17 @@ final class ProductivityAnalyticsStore: ObservableObject {
         )
     }
 
+    /// Focus seconds logged in the week containing `date`. `firstWeekday` uses `Calendar` numbering,
+    /// where 1 is Sunday and 2 is Monday.
+    func focusSecondsInWeek(containing date: Date = Date(), firstWeekday: Int, calendar: Calendar = .current) -> Int {
+        var weekCalendar = calendar
+        weekCalendar.firstWeekday = firstWeekday
+        guard let week = weekCalendar.dateInterval(of: .weekOfYear, for: date) else { return 0 }
+        return dailyAggregates
+            .filter { $0.dayStart >= week.start && $0.dayStart < week.end }
+            .reduce(0) { $0 + $1.totalFocusSeconds }
+    }
+
     func streakDays(calendar: Calendar = .current) -> Int {
         var streak = 0
         var currentDay = calendar.startOfDay(for: Date())
@@ -408,7 +448,7 @@ final class ProductivityAnalyticsStore: ObservableObject {
 
     private func publishAndPersist() {
         dailyAggregates = aggregateIndex.values.sorted { $0.dayStart < $1.dayStart }
-        guard let data = try? encoder.encode(dailyAggregates) else { return }
+        guard persists, let data = try? encoder.encode(dailyAggregates) else { return }
         try? data.write(to: fileURL)
     }
 
@@ -12,6 +12,8 @@ struct SessionRecord: Codable, Identifiable {
     let sessionType: SessionType
     let completed: Bool
     let interruptionCount: Int?
+    /// Wall-clock time spent paused. `durationSeconds` only counts time the timer was running.
+    let pausedSeconds: Int?
 
     init(
         startTime: Date,
@@ -20,7 +22,8 @@ struct SessionRecord: Codable, Identifiable {
         taskId: UUID?,
         sessionType: SessionType = .focus,
         completed: Bool = true,
-        interruptionCount: Int? = nil
+        interruptionCount: Int? = nil,
+        pausedSeconds: Int? = nil
     ) {
         self.id = UUID()
         self.startTime = startTime
@@ -30,6 +33,7 @@ struct SessionRecord: Codable, Identifiable {
         self.sessionType = sessionType
         self.completed = completed
         self.interruptionCount = interruptionCount
+        self.pausedSeconds = pausedSeconds
     }
 
     init(from decoder: Decoder) throws {
@@ -42,6 +46,7 @@ struct SessionRecord: Codable, Identifiable {
         sessionType = try container.decodeIfPresent(SessionType.self, forKey: .sessionType) ?? .focus
         completed = try container.decodeIfPresent(Bool.self, forKey: .completed) ?? true
         interruptionCount = try container.decodeIfPresent(Int.self, forKey: .interruptionCount)
+        pausedSeconds = try container.decodeIfPresent(Int.self, forKey: .pausedSeconds)
     }
 
     enum CodingKeys: String, CodingKey {
@@ -53,6 +58,7 @@ struct SessionRecord: Codable, Identifiable {
         case sessionType
         case completed
         case interruptionCount
+        case pausedSeconds
     }
 }
 
@@ -62,7 +68,10 @@ final class SessionRecordStore: ObservableObject {
     
     @Published private(set) var records: [SessionRecord] = []
     
-    private let fileURL: URL
+    let fileURL: URL
+    /// False for stores from `inMemory(analytics:)`, which never read or write `fileURL`.
+    private let persists: Bool
+    private let analytics: ProductivityAnalyticsStore
     private let decoder = JSONDecoder()
     private let encoder = JSONEncoder()
     
@@ -71,8 +80,21 @@ final class SessionRecordStore: ObservableObject {
         let dir = supportDir.appendingPathComponent("PomodoroApp", isDirectory: true)
         try? FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
         fileURL = dir.appendingPathComponent("session_records.json")
+        persists = true
+        analytics = .shared
         load()
-        ProductivityAnalyticsStore.shared.rebuild(from: records)
+        analytics.rebuild(from: records)
+    }
+
+    private init(inMemoryWith analytics: ProductivityAnalyticsStore) {
+        fileURL = URL(fileURLWithPath: "/dev/null")
+        persists = false
+        self.analytics = analytics
+    }
+
+    /// An empty store that keeps records only for its own lifetime, feeding `analytics`, e.g. for tests.
+    static func inMemory(analytics: ProductivityAnalyticsStore) -> SessionRecordStore {
+        SessionRecordStore(inMemoryWith: analytics)
     }
     
     func appendRecord(
@@ -82,7 +104,8 @@ final class SessionRecordStore: ObservableObject {
         taskId: UUID?,
         sessionType: SessionType = .focus,
         completed: Bool = true,
-        interruptionCount: Int? = nil
+        interruptionCount: Int? = nil,
+        pausedSeconds: Int? = nil
     ) {
         let record = SessionRecord(
             startTime: startTime,
@@ -91,13 +114,21 @@ final class SessionRecordStore: ObservableObject {
             taskId: taskId,
             sessionType: sessionType,
             completed: completed,
-            interruptionCount: interruptionCount
+            interruptionCount: interruptionCount,
+            pausedSeconds: pausedSeconds
         )
         records.append(record)
         save()
-        ProductivityAnalyticsStore.shared.ingest(record)
+        analytics.ingest(record)
     }
     
+    /// Swaps in a full history, e.g. when restoring a state snapshot.
+    func replaceRecords(_ newRecords: [SessionRecord]) {
+        records = newRecords
+        save()
+        analytics.rebuild(from: newRecords)
+    }
+
     /// Returns records within the last N days (inclusive of today).
     func records(lastDays: Int, calendar: Calendar = .current) -> [SessionRecord] {
         guard lastDays > 0 else { return [] }
@@ -105,6 +136,38 @@ final class SessionRecordStore: ObservableObject {
         return records.filter { $0.startTime >= start }
     }
     
+    /// Mean length of completed focus sessions, or 0 with no history.
+    /// Completed records store the nominal session length, so time spent paused is not reflected.
+    func averageCompletedFocusSeconds() -> Int {
+        var totalSeconds = 0
+        var count = 0
+        for record in records where record.sessionType == .focus && record.completed {
+            totalSeconds += record.durationSeconds
+            count += 1
+        }
+        guard count > 0 else { return 0 }
+        return totalSeconds / count
+    }
+
+    func completedFocusSessionCount() -> Int {
+        records.filter { $0.sessionType == .focus && $0.completed }.count
+    }
+
+    /// Drops records that started before the window `records(lastDays:)` would return, returning how
+    /// many were removed. Writes only when something was removed.
+    @discardableResult
+    func removeRecords(olderThanDays days: Int, now: Date = Date(), calendar: Calendar = .current) -> Int {
+        guard days > 0,
+              let cutoff = calendar.date(byAdding: .day, value: -(days - 1), to: calendar.startOfDay(for: now)) else {
+            return 0
+        }
+        let kept = records.filter { $0.startTime >= cutoff }
+        let removedCount = records.count - kept.count
+        guard removedCount > 0 else { return 0 }
+        replaceRecords(kept)
+        return removedCount
+    }
+
     /// Returns records for a specific day.
     func records(for day: Date, calendar: Calendar = .current) -> [SessionRecord] {
         let start = calendar.startOfDay(for: day)
@@ -113,16 +176,27 @@ final class SessionRecordStore: ObservableObject {
     }
     
     private func load() {
-        guard let data = try? Data(contentsOf: fileURL) else { return }
+        guard persists, let data = try? Data(contentsOf: fileURL) else { return }
         if let decoded = try? decoder.decode([SessionRecord].self, from: data) {
             records = decoded
-            ProductivityAnalyticsStore.shared.rebuild(from: decoded)
+            analytics.rebuild(from: decoded)
         }
     }
     
-    private func save() {
-        if let data = try? encoder.encode(records) {
-            try? data.write(to: fileURL)
+    /// Writes the records synchronously and reports whether the write succeeded.
+    @discardableResult
+    func flush() -> Bool {
+        guard persists else { return true }
+        guard let data = try? encoder.encode(records) else { return false }
+        do {
+            try data.write(to: fileURL, options: .atomic)
+            return true
+        } catch {
+            return false
         }
     }
+
+    private func save() {
+        flush()
+    }
 }
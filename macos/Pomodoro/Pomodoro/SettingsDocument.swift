@@ -0,0 +1,42 @@
+import Foundation
+
+enum SettingsDocumentError: LocalizedError {
+    case malformed
+
+    var errorDescription: String? {
+        switch self {
+        case .malformed:
+            return LocalizationManager.shared.text("settings_document.error.malformed")
+        }
+    }
+}
+
+/// Human-readable settings file for sharing a setup, e.g. with teammates. Unlike `StateSnapshot` it
+/// carries no stats or history, and every setting is a flat top-level key with plain JSON values so
+/// the file diffs cleanly and a different app version can apply the keys it understands.
+enum SettingsDocument {
+    static let currentVersion = 1
+    static let versionKey = "version"
+
+    static func write(_ settings: [String: Any], to url: URL) throws {
+        var document = settings
+        document[versionKey] = currentVersion
+        let data = try JSONSerialization.data(withJSONObject: document, options: [.prettyPrinted, .sortedKeys])
+        try data.write(to: url, options: .atomic)
+    }
+
+    /// Returns the settings without the version key. Files from newer versions are accepted; keys
+    /// this version doesn't know are left for the caller to report.
+    static func read(from url: URL) throws -> [String: Any] {
+        guard
+            let data = try? Data(contentsOf: url),
+            var document = (try? JSONSerialization.jsonObject(with: data)) as? [String: Any],
+            let version = document[versionKey] as? Int,
+            version >= 1
+        else {
+            throw SettingsDocumentError.malformed
+        }
+        document[versionKey] = nil
+        return document
+    }
+}
@@ -0,0 +1,86 @@
+import Foundation
+import UserNotifications
+
+/// Results of the "Check Setup" diagnostics. Running it sends one test notification and writes and
+/// removes a scratch file; no settings or timers are touched.
+struct SetupCheckReport {
+    struct Check {
+        let passed: Bool
+        let detail: String
+    }
+
+    let notifications: Check
+    let media: Check
+    let backend: Check
+    let storage: Check
+}
+
+@MainActor
+enum SetupCheck {
+    static func run(nowPlayingRouter: NowPlayingRouter) async -> SetupCheckReport {
+        async let backend = checkBackend()
+        let notifications = await checkNotifications()
+        let media = await checkMedia(nowPlayingRouter: nowPlayingRouter)
+        let storage = checkStorage()
+        return SetupCheckReport(
+            notifications: notifications,
+            media: media,
+            backend: await backend,
+            storage: storage
+        )
+    }
+
+    private static func checkNotifications() async -> SetupCheckReport.Check {
+        let l10n = LocalizationManager.shared
+        let center = UNUserNotificationCenter.current()
+        let settings = await center.notificationSettings()
+        guard settings.authorizationStatus == .authorized || settings.authorizationStatus == .provisional else {
+            return .init(passed: false, detail: l10n.text("setup_check.notifications.not_allowed"))
+        }
+        let content = UNMutableNotificationContent()
+        content.title = l10n.text("setup_check.notifications.test_title")
+        content.body = l10n.text("setup_check.notifications.test_body")
+        let request = UNNotificationRequest(identifier: UUID().uuidString, content: content, trigger: nil)
+        do {
+            try await center.add(request)
+            return .init(passed: true, detail: l10n.text("setup_check.notifications.sent"))
+        } catch {
+            return .init(passed: false, detail: error.localizedDescription)
+        }
+    }
+
+    private static func checkMedia(nowPlayingRouter: NowPlayingRouter) async -> SetupCheckReport.Check {
+        let l10n = LocalizationManager.shared
+        let permissions = PermissionsManager.shared
+        await permissions.refreshAutomationStatus()
+        guard MediaBackendInfo(automationStatus: permissions.automationStatus).canControl else {
+            return .init(passed: false, detail: l10n.text("setup_check.media.automation_denied"))
+        }
+        guard nowPlayingRouter.isAvailable else {
+            return .init(passed: true, detail: l10n.text("setup_check.media.no_player"))
+        }
+        return .init(passed: true, detail: l10n.format("setup_check.media.player", nowPlayingRouter.sourceName))
+    }
+
+    private static func checkBackend() async -> SetupCheckReport.Check {
+        do {
+            try await APIClient.checkReachability()
+            return .init(passed: true, detail: LocalizationManager.shared.text("setup_check.backend.reachable"))
+        } catch {
+            return .init(passed: false, detail: error.localizedDescription)
+        }
+    }
+
+    private static func checkStorage() -> SetupCheckReport.Check {
+        let l10n = LocalizationManager.shared
+        let directory = SessionRecordStore.shared.fileURL.deletingLastPathComponent()
+        let probeURL = directory.appendingPathComponent(".setup-check-\(UUID().uuidString)")
+        do {
+            try Data().write(to: probeURL)
+            try FileManager.default.removeItem(at: probeURL)
+            return .init(passed: true, detail: l10n.format("setup_check.storage.writable", directory.path))
+        } catch {
+            return .init(passed: false, detail: error.localizedDescription)
+        }
+    }
+}
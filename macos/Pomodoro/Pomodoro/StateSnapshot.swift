@@ -0,0 +1,97 @@
+import Foundation
+
+enum StateSnapshotError: LocalizedError {
+    case malformed
+    case unsupportedVersion(Int)
+    case timerActive
+
+    var errorDescription: String? {
+        switch self {
+        case .malformed:
+            return LocalizationManager.shared.text("snapshot.error.malformed")
+        case .unsupportedVersion(let version):
+            return LocalizationManager.shared.format("snapshot.error.unsupported_version", version)
+        case .timerActive:
+            return LocalizationManager.shared.text("snapshot.error.timer_active")
+        }
+    }
+}
+
+/// Portable copy of timer settings, preferences, today's stats and session history, used to back up
+/// or move the app's state between Macs. Live timer progress is deliberately left out: a snapshot
+/// only restores while both timers are idle.
+struct StateSnapshot: Codable {
+    static let currentVersion = 2
+
+    /// Every setting, in the same flat layout `exportSettings(to:)` writes, so a new setting is
+    /// carried by snapshots as soon as it is added there.
+    struct Preferences: Codable {
+        let settingsJSON: Data
+
+        init(_ settings: [String: Any]) throws {
+            settingsJSON = try JSONSerialization.data(withJSONObject: settings, options: .sortedKeys)
+        }
+
+        func settings() throws -> [String: Any] {
+            guard let settings = (try? JSONSerialization.jsonObject(with: settingsJSON)) as? [String: Any] else {
+                throw StateSnapshotError.malformed
+            }
+            return settings
+        }
+    }
+
+    let version: Int
+    let exportedAt: Date
+    let preferences: Preferences
+    let dailyStats: DailyStats
+    let sessionRecords: [SessionRecord]
+
+    init(preferences: Preferences, dailyStats: DailyStats, sessionRecords: [SessionRecord]) {
+        self.version = Self.currentVersion
+        self.exportedAt = Date()
+        self.preferences = preferences
+        self.dailyStats = dailyStats
+        self.sessionRecords = sessionRecords
+    }
+
+    func encodedBlob() throws -> String {
+        try Self.makeEncoder().encode(self).base64EncodedString()
+    }
+
+    /// Decodes a blob produced by `encodedBlob()`, rejecting anything from another snapshot version.
+    static func decode(blob: String) throws -> StateSnapshot {
+        let trimmedBlob = blob.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard let data = Data(base64Encoded: trimmedBlob) else {
+            throw StateSnapshotError.malformed
+        }
+        let decoder = makeDecoder()
+        // Read the version on its own first so a newer layout is reported as such, not as corrupt.
+        guard let header = try? decoder.decode(Header.self, from: data) else {
+            throw StateSnapshotError.malformed
+        }
+        guard header.version == currentVersion else {
+            throw StateSnapshotError.unsupportedVersion(header.version)
+        }
+        do {
+            return try decoder.decode(StateSnapshot.self, from: data)
+        } catch {
+            throw StateSnapshotError.malformed
+        }
+    }
+
+    private struct Header: Decodable {
+        let version: Int
+    }
+
+    private static func makeEncoder() -> JSONEncoder {
+        let encoder = JSONEncoder()
+        encoder.dateEncodingStrategy = .iso8601
+        return encoder
+    }
+
+    private static func makeDecoder() -> JSONDecoder {
+        let decoder = JSONDecoder()
+        decoder.dateDecodingStrategy = .iso8601
+        return decoder
+    }
+}
@@ -0,0 +1,33 @@
+import Foundation
+
+/// Rolling timings for a timer engine's once-a-second tick. The tick total includes the
+/// subscribers that run synchronously when `remainingSeconds` publishes (the "emit"), which is
+/// where the per-second cost of state handlers shows up.
+struct TickPerformanceStats {
+    private static let windowSize = 30
+
+    private(set) var lastTickDate: Date?
+    private(set) var lastEmitDurationMilliseconds: Double = 0
+    private var recentTickDurations: [Double] = []
+
+    var averageTickDurationMilliseconds: Double {
+        guard !recentTickDurations.isEmpty else { return 0 }
+        return recentTickDurations.reduce(0, +) / Double(recentTickDurations.count)
+    }
+
+    mutating func recordTick(startedAt start: DispatchTime) {
+        lastTickDate = Date()
+        recentTickDurations.append(Self.milliseconds(since: start))
+        if recentTickDurations.count > Self.windowSize {
+            recentTickDurations.removeFirst()
+        }
+    }
+
+    mutating func recordEmit(startedAt start: DispatchTime) {
+        lastEmitDurationMilliseconds = Self.milliseconds(since: start)
+    }
+
+    private static func milliseconds(since start: DispatchTime) -> Double {
+        Double(DispatchTime.now().uptimeNanoseconds - start.uptimeNanoseconds) / 1_000_000
+    }
+}
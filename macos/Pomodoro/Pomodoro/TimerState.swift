@@ -14,3 +14,80 @@ enum TimerState: String {
     case breakRunning
     case breakPaused
 }
+
+/// Which number the timer displays show. Progress and notifications always use the remaining time.
+enum TimerDisplayMode: String, CaseIterable, Identifiable {
+    case remaining
+    case elapsed
+
+    var id: String { rawValue }
+
+    var title: String {
+        switch self {
+        case .remaining:
+            return LocalizationManager.shared.text("timer.display.remaining")
+        case .elapsed:
+            return LocalizationManager.shared.text("timer.display.elapsed")
+        }
+    }
+}
+
+/// What resetting the Pomodoro clears. `.cycle` stops at an idle work session with the long-break
+/// count back at zero. `.session` keeps the mode and cycle progress and reloads the current
+/// session's full length, left paused.
+enum PomodoroResetScope: String, CaseIterable, Identifiable {
+    case cycle
+    case session
+
+    var id: String { rawValue }
+
+    var title: String {
+        switch self {
+        case .cycle:
+            return LocalizationManager.shared.text("timer.reset_scope.cycle")
+        case .session:
+            return LocalizationManager.shared.text("timer.reset_scope.session")
+        }
+    }
+}
+
+/// The engine's view of "now" and when the ticking session will reach zero, for checking that an
+/// external display stays within a second of the timer.
+struct TimerClockReading: Equatable {
+    let now: Date
+    /// Nil unless a session is counting down. Only as precise as the one-second tick.
+    let activeDeadline: Date?
+}
+
+/// Minimal, stable timer summary for constrained companion clients. `encoded` is always 6 bytes:
+///
+///     byte 0     mode: 0 idle, 1 work, 2 short break, 3 long break
+///     byte 1     running: 1 while counting down, otherwise 0
+///     bytes 2-3  remaining seconds, big-endian UInt16, capped at 65535
+///     bytes 4-5  progress through the session in permille, big-endian UInt16, 0...1000
+///
+/// New fields must be appended so existing clients keep decoding the first six bytes.
+struct CompactTimerState: Equatable {
+    let mode: UInt8
+    let isRunning: Bool
+    let remainingSeconds: UInt16
+    let progressPermille: UInt16
+
+    var encoded: Data {
+        Data([
+            mode,
+            isRunning ? 1 : 0,
+            UInt8(remainingSeconds >> 8),
+            UInt8(remainingSeconds & 0xFF),
+            UInt8(progressPermille >> 8),
+            UInt8(progressPermille & 0xFF)
+        ])
+    }
+}
+
+/// A session waiting out its auto-start delay at 00:00, e.g. to drive a "Break in 5…" toast.
+struct AutoStartGraceState: Equatable {
+    let upcomingMode: PomodoroTimerEngine.Mode
+    /// Rounded up, so it reads 1 until the moment the session starts.
+    let secondsLeft: Int
+}
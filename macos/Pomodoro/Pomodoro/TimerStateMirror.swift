@@ -0,0 +1,99 @@
+import Combine
+import Foundation
+
+/// JSON written to `AppState.stateMirrorPath`. Fields may be added but not renamed, since scripts
+/// outside the app read them.
+struct TimerStateMirrorDocument: Codable, Equatable {
+    struct Pomodoro: Codable, Equatable {
+        let state: String
+        let mode: String
+        let remainingSeconds: Int
+        let completedWorkSessions: Int
+        /// Unix time in seconds when the ticking session reaches zero, from
+        /// `PomodoroTimerEngine.clockReading()`. Left out unless a session is counting down.
+        let activeDeadline: Int?
+        /// `PomodoroTimerEngine.compactState` as base64, for companion clients that relay the file.
+        let compact: String
+    }
+
+    struct Countdown: Codable, Equatable {
+        let state: String
+        let remainingSeconds: Int
+    }
+
+    let pomodoro: Pomodoro
+    let countdown: Countdown
+}
+
+/// Keeps the file at `AppState.stateMirrorPath` in step with both timers, so shell scripts can
+/// watch it with fswatch or similar. Each write replaces the file atomically, and unchanged state
+/// isn't written again.
+@MainActor
+final class TimerStateMirror {
+    private unowned let appState: AppState
+    private var cancellables: Set<AnyCancellable> = []
+    private var lastWritten: (path: String, data: Data)?
+    private let encoder: JSONEncoder = {
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+        return encoder
+    }()
+
+    init(appState: AppState) {
+        self.appState = appState
+
+        let pomodoro = appState.pomodoro
+        let countdown = appState.countdown
+        Publishers.MergeMany(
+            pomodoro.$state.map { _ in () }.eraseToAnyPublisher(),
+            pomodoro.$mode.map { _ in () }.eraseToAnyPublisher(),
+            pomodoro.$remainingSeconds.map { _ in () }.eraseToAnyPublisher(),
+            pomodoro.$completedWorkSessions.map { _ in () }.eraseToAnyPublisher(),
+            countdown.$state.map { _ in () }.eraseToAnyPublisher(),
+            countdown.$remainingSeconds.map { _ in () }.eraseToAnyPublisher(),
+            appState.$stateMirrorPath.map { _ in () }.eraseToAnyPublisher()
+        )
+        // @Published emits before the property is set; hop so the new values are readable.
+        .receive(on: RunLoop.main)
+        .sink { [weak self] in
+            self?.writeIfChanged()
+        }
+        .store(in: &cancellables)
+    }
+
+    private func writeIfChanged() {
+        guard let path = appState.stateMirrorPath else {
+            lastWritten = nil
+            return
+        }
+        let pomodoro = appState.pomodoro
+        let countdown = appState.countdown
+        // Whole seconds, so the deadline only changes when the timer is adjusted, not with each write.
+        let activeDeadline = pomodoro.clockReading().activeDeadline.map { Int($0.timeIntervalSince1970.rounded()) }
+        let document = TimerStateMirrorDocument(
+            pomodoro: .init(
+                state: pomodoro.state.rawValue,
+                mode: pomodoro.mode.rawValue,
+                remainingSeconds: pomodoro.remainingSeconds,
+                completedWorkSessions: pomodoro.completedWorkSessions,
+                activeDeadline: activeDeadline,
+                compact: pomodoro.compactState.encoded.base64EncodedString()
+            ),
+            countdown: .init(
+                state: countdown.state.rawValue,
+                remainingSeconds: countdown.remainingSeconds
+            )
+        )
+        guard let data = try? encoder.encode(document) else { return }
+        if let lastWritten, lastWritten.path == path, lastWritten.data == data {
+            return
+        }
+        do {
+            // `.atomic` writes a temporary file and renames it over the target.
+            try data.write(to: URL(fileURLWithPath: path), options: .atomic)
+            lastWritten = (path, data)
+        } catch {
+            print("[TimerStateMirror] Failed to write \(path): \(error.localizedDescription)")
+        }
+    }
+}
@@ -182,7 +182,7 @@ private struct DayColumnView: View {
             guard let start = event.startDate else {
                 return localizationManager.text("calendar.no_due_time")
             }
-            DayColumnView.timeFormatter.locale = localizationManager.effectiveLocale
+            localizationManager.configureTimeFormatter(DayColumnView.timeFormatter)
             let formatter = DayColumnView.timeFormatter
             guard let end = event.endDate else {
                 return formatter.string(from: start)
@@ -229,7 +229,7 @@ private struct DayColumnView: View {
             if event.isAllDay {
                 return localizationManager.text("calendar.all_day")
             }
-            DayColumnView.timeFormatter.locale = localizationManager.effectiveLocale
+            localizationManager.configureTimeFormatter(DayColumnView.timeFormatter)
             let formatter = DayColumnView.timeFormatter
             return "\(formatter.string(from: event.startDate)) – \(formatter.string(from: event.endDate))"
         }
@@ -267,7 +267,7 @@ private struct DayColumnView: View {
         }
         
         private func timeRange(from start: Date, duration: Int?) -> String {
-            DayColumnView.timeFormatter.locale = localizationManager.effectiveLocale
+            localizationManager.configureTimeFormatter(DayColumnView.timeFormatter)
             let formatter = DayColumnView.timeFormatter
             let end = start.addingTimeInterval(Double((duration ?? 30) * 60))
             return "\(formatter.string(from: start)) – \(formatter.string(from: end))"
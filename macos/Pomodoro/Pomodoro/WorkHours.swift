@@ -0,0 +1,61 @@
+import Foundation
+
+/// Local times of day, per weekday, during which a finished break may roll into a new work session
+/// on its own. Starting a session by hand is never restricted.
+struct WorkHours: Equatable {
+    struct Window: Equatable {
+        /// Minutes after local midnight; `startMinute` is inclusive and `endMinute` exclusive.
+        let startMinute: Int
+        let endMinute: Int
+
+        /// Nil unless the window lies within one day and ends after it starts.
+        init?(startMinute: Int, endMinute: Int) {
+            guard startMinute >= 0, endMinute <= 24 * 60, startMinute < endMinute else { return nil }
+            self.startMinute = startMinute
+            self.endMinute = endMinute
+        }
+    }
+
+    /// Keyed by `Calendar` weekday, 1 being Sunday. Days without a window never auto-start.
+    let windows: [Int: Window]
+
+    init(windows: [Int: Window]) {
+        self.windows = windows.filter { (1...7).contains($0.key) }
+    }
+
+    /// The same window on each of `weekdays`.
+    init(weekdays: Set<Int>, window: Window) {
+        self.init(windows: Dictionary(uniqueKeysWithValues: weekdays.map { ($0, window) }))
+    }
+
+    /// Monday to Friday, 9:00 to 17:00.
+    static let standard = WorkHours(
+        weekdays: [2, 3, 4, 5, 6],
+        window: Window(startMinute: 9 * 60, endMinute: 17 * 60)!
+    )
+
+    func contains(_ date: Date, calendar: Calendar = .current) -> Bool {
+        guard let window = windows[calendar.component(.weekday, from: date)] else { return false }
+        let components = calendar.dateComponents([.hour, .minute], from: date)
+        let minute = (components.hour ?? 0) * 60 + (components.minute ?? 0)
+        return minute >= window.startMinute && minute < window.endMinute
+    }
+
+    init?(dictionary: [String: Any]) {
+        var windows: [Int: Window] = [:]
+        for (key, value) in dictionary {
+            guard let weekday = Int(key),
+                  let entry = value as? [String: Int],
+                  let start = entry["start"], let end = entry["end"],
+                  let window = Window(startMinute: start, endMinute: end) else { return nil }
+            windows[weekday] = window
+        }
+        self.init(windows: windows)
+    }
+
+    var dictionary: [String: Any] {
+        Dictionary(uniqueKeysWithValues: windows.map { weekday, window in
+            (String(weekday), ["start": window.startMinute, "end": window.endMinute])
+        })
+    }
+}